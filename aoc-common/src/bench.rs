@@ -0,0 +1,82 @@
+use crate::Solution;
+use criterion::measurement::WallTime;
+use criterion::{BenchmarkGroup, BenchmarkId, Criterion, SamplingMode};
+use std::hint::black_box;
+
+// Re-exported so the days' bench targets only need `aoc-common` with the `bench` feature.
+pub use criterion;
+
+// Define a day's whole bench target. Each input is a name, read with `read_input` unless it is
+// followed by `=> <text>` to bench that text instead, like a puzzle example:
+//
+//     aoc_common::bench_main!(Day10, ["input", "test_input"]);
+//     aoc_common::bench_main!(Day6, ["input", "example" => "mjqjpqmgbljsphdztnvjfqwrcgsmlb"]);
+//     aoc_common::bench_main!(Day12, ["input"], sample_size = 10);
+#[macro_export]
+macro_rules! bench_main {
+    ($solution:ty, [$($name:literal $(=> $text:expr)?),+ $(,)?] $(, sample_size = $samples:expr)?) => {
+        fn bench(c: &mut $crate::bench::criterion::Criterion) {
+            let samples: Option<usize> = None $(.or(Some($samples)))?;
+            $crate::bench::bench_day::<$solution>(
+                c,
+                env!("CARGO_PKG_NAME"),
+                samples,
+                &[$(($name, $crate::bench_main!(@text $name $($text)?))),+],
+            );
+        }
+
+        $crate::bench::criterion::criterion_group!(benches, bench);
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+    (@text $name:literal) => {
+        $crate::read_input($name).as_str()
+    };
+    (@text $name:literal $text:expr) => {
+        $text
+    };
+}
+
+// Benchmark a day on each of `inputs`, given as `(name, text)` pairs, in a group named after the
+// day. Some parts take whole seconds, so those days pass a small `sample_size` and are sampled in
+// flat mode, which Criterion recommends for long-running benchmarks.
+pub fn bench_day<S: Solution>(
+    c: &mut Criterion,
+    day: &str,
+    sample_size: Option<usize>,
+    inputs: &[(&str, &str)],
+) {
+    let mut group = c.benchmark_group(day);
+    if let Some(samples) = sample_size {
+        group.sample_size(samples).sampling_mode(SamplingMode::Flat);
+    }
+    for (name, input) in inputs {
+        bench_solution::<S>(&mut group, name, input);
+    }
+    group.finish();
+}
+
+// Benchmark parsing and both parts of a day's solution on one input. The benchmarks are named
+// `parse/<input>`, `part1/<input>` and `part2/<input>` within the day's group, so a baseline
+// saved with `cargo bench -p dayN --bench dayN -- --save-baseline <name>` can be compared
+// against later with `cargo bench -p dayN --bench dayN -- --baseline <name>`.
+pub fn bench_solution<S: Solution>(
+    group: &mut BenchmarkGroup<WallTime>,
+    input_name: &str,
+    input: &str,
+) {
+    group.bench_with_input(BenchmarkId::new("parse", input_name), input, |b, input| {
+        b.iter(|| S::parse(black_box(input)))
+    });
+
+    let parsed = S::parse(input).unwrap_or_else(|e| panic!("{}", e.annotate(input, input_name)));
+    group.bench_with_input(
+        BenchmarkId::new("part1", input_name),
+        &parsed,
+        |b, parsed| b.iter(|| S::part1(black_box(parsed))),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("part2", input_name),
+        &parsed,
+        |b, parsed| b.iter(|| S::part2(black_box(parsed))),
+    );
+}
@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt;
+
+// A puzzle input that couldn't be parsed. Lines and columns are 1-based, and an empty `found`
+// means the parser ran off the end of the line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    // An error at a byte offset into a line, taking whatever token is at that offset (a word or
+    // number, or else a single character) as the text that was found.
+    pub fn in_line(line: usize, text: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let column = text[..offset].chars().count() + 1;
+        let rest = &text[offset..];
+
+        // Allow a sign in front of a number.
+        let sign_len = match rest.strip_prefix(['-', '+']) {
+            Some(unsigned) if unsigned.starts_with(char::is_alphanumeric) => 1,
+            _ => 0,
+        };
+        let word_len = rest[sign_len..]
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(rest.len() - sign_len);
+        let token_len = match rest.chars().next() {
+            Some(c) if word_len == 0 => c.len_utf8(),
+            _ => sign_len + word_len,
+        };
+
+        ParseError::new(line, column, expected, &rest[..token_len])
+    }
+
+    // An error covering a whole line that didn't have the expected format.
+    pub fn bad_line(line: usize, text: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(line, 1, expected, text)
+    }
+
+    // Render the error along with the offending line of input, with the problem column marked.
+    pub fn annotate(&self, input: &str, source_name: &str) -> String {
+        let mut out = format!(
+            "error: {}\n --> {}:{}:{}\n",
+            self, source_name, self.line, self.column
+        );
+
+        if let Some(text) = input.lines().nth(self.line.wrapping_sub(1)) {
+            let number = self.line.to_string();
+            let gutter = " ".repeat(number.len());
+            let width = self.found.chars().count().max(1);
+            out.push_str(&format!("{} |\n", gutter));
+            out.push_str(&format!("{} | {}\n", number, text));
+            out.push_str(&format!(
+                "{} | {}{}\n",
+                gutter,
+                " ".repeat(self.column - 1),
+                "^".repeat(width)
+            ));
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.found.is_empty() {
+            write!(f, "expected {}, found end of line", self.expected)
+        } else {
+            write!(f, "expected {}, found `{}`", self.expected, self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// A part that couldn't be answered even though its input parsed, like day 11's worry levels
+// growing too big to track.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for SolveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_line_test() {
+        let err = ParseError::in_line(3, "2-4,x7-8", 4, "a number");
+        assert_eq!(err, ParseError::new(3, 5, "a number", "x7"));
+        assert_eq!(err.to_string(), "expected a number, found `x7`");
+
+        let err = ParseError::in_line(1, "addx", 4, "a value");
+        assert_eq!(err.to_string(), "expected a value, found end of line");
+
+        let err = ParseError::in_line(1, "addx -5x", 5, "a value");
+        assert_eq!(err.found, "-5x");
+
+        let err = ParseError::in_line(1, "[1,;]", 3, "a value");
+        assert_eq!(err.found, ";");
+    }
+
+    #[test]
+    fn annotate_test() {
+        let input = "2-4,6-8\n2-4,x7-8\n";
+        let err = ParseError::in_line(2, "2-4,x7-8", 4, "a number");
+        assert_eq!(
+            err.annotate(input, "input"),
+            "error: expected a number, found `x7`\n --> input:2:5\n  |\n2 | 2-4,x7-8\n  |     ^^\n"
+        );
+    }
+}
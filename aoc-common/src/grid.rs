@@ -0,0 +1,290 @@
+use crate::{ParseError, Point};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// Offsets to the orthogonal neighbours of a cell: up, right, down, left.
+pub const DIRECTIONS4: [Point; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// Offsets to all eight neighbours of a cell, clockwise from up.
+pub const DIRECTIONS8: [Point; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+// A dense, rectangular grid of cells addressed by `(x, y)`, with `(0, 0)` in the top left and
+// `y` increasing downwards.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Build a grid from its rows, or `None` if they aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    // Parse a grid from a map with one character per cell, where `cell` converts a character, or
+    // returns `None` if it isn't valid. `expected` describes the valid characters in errors.
+    pub fn parse<F>(lines: &[String], expected: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (index, line) in lines.iter().enumerate() {
+            // The grid must be rectangular.
+            let wrong_width = |offset| {
+                let expected = format!("a row of {} cells", width);
+                ParseError::in_line(index + 1, line, offset, expected)
+            };
+
+            let mut row_width = 0;
+            for (offset, c) in line.char_indices() {
+                if row_width == width {
+                    return Err(wrong_width(offset));
+                }
+                let value = cell(c)
+                    .ok_or_else(|| ParseError::in_line(index + 1, line, offset, expected))?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            if row_width < width {
+                return Err(wrong_width(line.len()));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Point<usize>) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Point<usize>) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    // The position one step from `pos` in direction `dir`, if it's inside the grid.
+    pub fn step(&self, pos: Point<usize>, dir: Point) -> Option<Point<usize>> {
+        let x = pos.0.checked_add_signed(dir.0 as isize)?;
+        let y = pos.1.checked_add_signed(dir.1 as isize)?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    // The orthogonal neighbours of a position that are inside the grid.
+    pub fn neighbours4(&self, pos: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        DIRECTIONS4
+            .iter()
+            .filter_map(move |&dir| self.step(pos, dir))
+    }
+
+    // All eight neighbours of a position that are inside the grid, including diagonals.
+    pub fn neighbours8(&self, pos: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        DIRECTIONS8
+            .iter()
+            .filter_map(move |&dir| self.step(pos, dir))
+    }
+
+    // Every position from `pos` (exclusive) to the edge of the grid in direction `dir`.
+    pub fn ray(&self, pos: Point<usize>, dir: Point) -> impl Iterator<Item = Point<usize>> + '_ {
+        let mut current = pos;
+        std::iter::from_fn(move || {
+            current = self.step(current, dir)?;
+            Some(current)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside the grid", x);
+        self.cells[x..].iter().step_by(self.width).take(self.height)
+    }
+
+    // Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // Every cell in the grid along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // Add a row to the bottom of the grid, growing its height by one.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let len = self.cells.len();
+        self.cells.extend(row);
+        assert_eq!(
+            self.cells.len() - len,
+            self.width,
+            "Row doesn't match the grid width"
+        );
+        self.height += 1;
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point<usize>) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Point<usize>) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+// Renders one line per row, with no trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn example() -> Grid<char> {
+        let lines = vec![String::from("abc"), String::from("def")];
+        Grid::parse(&lines, "a letter", |c| c.is_alphabetic().then_some(c)).unwrap()
+    }
+
+    #[test]
+    fn parse_test() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let lines = vec![String::from("abc"), String::from("d?f")];
+        let err = Grid::parse(&lines, "a letter", |c| c.is_alphabetic().then_some(c)).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "?"));
+
+        let lines = vec![String::from("abc"), String::from("de")];
+        let err = Grid::parse(&lines, "a letter", |c| c.is_alphabetic().then_some(c)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "a row of 3 cells");
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let grid = example();
+        let corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+
+        let edge: Vec<_> = grid.neighbours8((1, 0)).collect();
+        assert_eq!(edge, vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]);
+    }
+
+    #[test]
+    fn lines_test() {
+        let grid = example();
+        assert_eq!(grid.row(1).collect::<String>(), "def");
+        assert_eq!(grid.column(1).rev().collect::<String>(), "eb");
+
+        let ray: Vec<_> = grid.ray((0, 0), (1, 0)).collect();
+        assert_eq!(ray, vec![(1, 0), (2, 0)]);
+        assert_eq!(grid.ray((0, 1), (1, -1)).count(), 1);
+    }
+
+    #[test]
+    fn push_row_test() {
+        let mut grid = Grid::new(2, 0, false);
+        grid.push_row([true, false]);
+        assert_eq!(grid.height(), 1);
+        assert!(grid[(0, 0)]);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_display_roundtrip(rows in prop::collection::vec("[a-z]{3}", 1..6)) {
+            let grid = Grid::parse(&rows, "a letter", |c| c.is_alphabetic().then_some(c)).unwrap();
+            prop_assert_eq!(grid.to_string(), rows.join("\n"));
+            prop_assert_eq!(grid.positions().count(), 3 * rows.len());
+        }
+
+        #[test]
+        fn step_stays_inside(width in 1..6usize, height in 1..6usize, x in 0..6usize, y in 0..6usize) {
+            let grid = Grid::new(width, height, ());
+            let pos = (x % width, y % height);
+            for dir in DIRECTIONS8 {
+                if let Some((nx, ny)) = grid.step(pos, dir) {
+                    prop_assert!(nx < width && ny < height);
+                    prop_assert_eq!((nx as i32 - pos.0 as i32, ny as i32 - pos.1 as i32), dir);
+                }
+            }
+            prop_assert!(grid.neighbours4(pos).count() <= grid.neighbours8(pos).count());
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::ParseError;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+
+// Read a whole input file into a string.
+pub fn read_input(filename: &str) -> String {
+    fs::read_to_string(filename).unwrap_or_else(|e| panic!("Failed to read {}: {}", filename, e))
+}
+
+// Read the input file named by the first command line argument, or stdin if there isn't one.
+// Also returns where the input was read from, to use in error messages.
+pub fn read_input_arg() -> (String, String) {
+    read_input_or_stdin(env::args().nth(1).as_deref())
+}
+
+// Read the named input file, or stdin if there isn't one, along with where it was read from.
+pub fn read_input_or_stdin(filename: Option<&str>) -> (String, String) {
+    match filename {
+        Some(filename) => (read_input(filename), String::from(filename)),
+        None => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .unwrap_or_else(|e| panic!("Failed to read stdin: {}", e));
+            (input, String::from("<stdin>"))
+        }
+    }
+}
+
+// Split an input into lines, with surrounding whitespace trimmed. Blank lines are kept, for
+// inputs that use them as separators.
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines().map(|l| String::from(l.trim())).collect()
+}
+
+// Split an input into its non-blank lines, with surrounding whitespace trimmed.
+pub fn non_empty_lines(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .map(|l| String::from(l.trim()))
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+// Read every line of a file, keeping blank lines.
+pub fn read_lines(filename: &str) -> Vec<String> {
+    lines(&read_input(filename))
+}
+
+// Read the non-blank lines of a file.
+pub fn read_file(filename: &str) -> Vec<String> {
+    non_empty_lines(&read_input(filename))
+}
+
+// Line parsers report errors against the trimmed lines they were given, so keep track of where
+// each of those lines came from in the original input, and fix up any error to point there.
+fn parse_mapped<T, F>(input: &str, keep_blank: bool, parse: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&Vec<String>) -> Result<T, ParseError>,
+{
+    let mut lines = Vec::new();
+    let mut origins = Vec::new();
+
+    for (index, raw) in input.lines().enumerate() {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() && !keep_blank {
+            continue;
+        }
+
+        let indent = raw[..raw.len() - raw.trim_start().len()].chars().count();
+        lines.push(String::from(trimmed));
+        origins.push((index + 1, indent));
+    }
+
+    parse(&lines).map_err(|mut e| {
+        if let Some(&(line, indent)) = origins.get(e.line.wrapping_sub(1)) {
+            e.line = line;
+            e.column += indent;
+        }
+        e
+    })
+}
+
+// Run a line parser over every line of an input, as split by `lines`.
+pub fn parse_all_lines<T, F>(input: &str, parse: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&Vec<String>) -> Result<T, ParseError>,
+{
+    parse_mapped(input, true, parse)
+}
+
+// Run a line parser over the non-blank lines of an input, as split by `non_empty_lines`.
+pub fn parse_non_empty_lines<T, F>(input: &str, parse: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&Vec<String>) -> Result<T, ParseError>,
+{
+    parse_mapped(input, false, parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_non_empty_lines_test() {
+        let input = "1,2\n\n  3,x\n";
+        let result: Result<(), ParseError> = parse_non_empty_lines(input, |lines| {
+            assert_eq!(lines, &vec![String::from("1,2"), String::from("3,x")]);
+            Err(ParseError::in_line(2, &lines[1], 2, "a number"))
+        });
+
+        assert_eq!(result, Err(ParseError::new(3, 5, "a number", "x")));
+    }
+}
@@ -0,0 +1,196 @@
+use std::cmp;
+use std::fmt;
+use std::ops::{Add, Sub};
+
+// A non-empty, inclusive range of integers `start..=end`, like the section ranges `2-4` in the
+// puzzles. Operations whose result might be empty return `Option`s or lists of intervals.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Interval<T = i64> {
+    start: T,
+    end: T,
+}
+
+impl<T> Interval<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "An interval can't end before it starts");
+        Interval { start, end }
+    }
+
+    // The interval from `start` to `end`, or `None` if that would be empty.
+    pub fn try_new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    // The number of values in the interval, which is never zero.
+    pub fn len(&self) -> T {
+        self.end - self.start + T::from(1)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    // Whether every value in `other` is also in this interval.
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::try_new(
+            cmp::max(self.start, other.start),
+            cmp::min(self.end, other.end),
+        )
+    }
+
+    // Both intervals as a single interval, or `None` if there's a gap between them. Intervals
+    // that are next to each other, like `2-4` and `5-6`, join up.
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        if second.start > first.end && second.start - first.end > T::from(1) {
+            return None;
+        }
+        Some(Interval {
+            start: first.start,
+            end: cmp::max(first.end, second.end),
+        })
+    }
+
+    // The values in this interval but not in `other`, as up to two intervals, lowest first.
+    pub fn difference(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+
+        let one = T::from(1);
+        let mut pieces = Vec::with_capacity(2);
+        if self.start < other.start {
+            pieces.push(Interval::new(self.start, other.start - one));
+        }
+        if other.end < self.end {
+            pieces.push(Interval::new(other.end + one, self.end));
+        }
+        pieces
+    }
+
+    // Every value in the interval, in order.
+    pub fn values(&self) -> impl Iterator<Item = T> {
+        let (end, one) = (self.end, T::from(1));
+        let mut next = Some(self.start);
+        std::iter::from_fn(move || {
+            let value = next?;
+            next = (value < end).then(|| value + one);
+            Some(value)
+        })
+    }
+}
+
+// Written like the puzzles' ranges, `start-end`.
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn single_value_test() {
+        let six = Interval::new(6, 6);
+        assert_eq!(six.len(), 1);
+        assert!(six.contains(6) && !six.contains(5) && !six.contains(7));
+        assert!(Interval::new(4, 6).contains_interval(&six));
+        assert!(!six.contains_interval(&Interval::new(4, 6)));
+        assert_eq!(six.intersection(&Interval::new(6, 8)), Some(six));
+        assert_eq!(six.intersection(&Interval::new(7, 8)), None);
+        assert_eq!(six.union(&Interval::new(7, 8)), Some(Interval::new(6, 8)));
+        assert_eq!(six.difference(&six), vec![]);
+        assert_eq!(six.values().collect::<Vec<_>>(), vec![6]);
+        assert_eq!(six.to_string(), "6-6");
+    }
+
+    #[test]
+    fn difference_test() {
+        let outer = Interval::new(2, 8);
+        assert_eq!(
+            outer.difference(&Interval::new(4, 5)),
+            vec![Interval::new(2, 3), Interval::new(6, 8)]
+        );
+        assert_eq!(
+            outer.difference(&Interval::new(0, 4)),
+            vec![Interval::new(5, 8)]
+        );
+        assert_eq!(outer.difference(&Interval::new(9, 9)), vec![outer]);
+    }
+
+    #[test]
+    fn union_test() {
+        let low = Interval::new(2u32, 4);
+        assert_eq!(low.union(&Interval::new(5, 6)), Some(Interval::new(2, 6)));
+        assert_eq!(low.union(&Interval::new(6, 7)), None);
+        assert_eq!(Interval::new(3, 3).union(&low), Some(low));
+        assert_eq!(Interval::try_new(5u32, 4), None);
+    }
+
+    fn interval() -> impl Strategy<Value = Interval<i32>> {
+        (-20..20i32, 0..10i32).prop_map(|(start, len)| Interval::new(start, start + len))
+    }
+
+    fn values(interval: &Interval<i32>) -> BTreeSet<i32> {
+        (interval.start()..=interval.end()).collect()
+    }
+
+    fn values_of(intervals: &[Interval<i32>]) -> BTreeSet<i32> {
+        intervals.iter().flat_map(values).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_sets(a in interval(), b in interval(), value in -25..35i32) {
+            let (set_a, set_b) = (values(&a), values(&b));
+
+            prop_assert_eq!(a.len() as usize, set_a.len());
+            prop_assert_eq!(a.values().collect::<BTreeSet<_>>(), set_a.clone());
+            prop_assert_eq!(a.contains(value), set_a.contains(&value));
+            prop_assert_eq!(a.contains_interval(&b), set_b.is_subset(&set_a));
+            prop_assert_eq!(a.overlaps(&b), !set_a.is_disjoint(&set_b));
+
+            let intersection: BTreeSet<i32> = set_a.intersection(&set_b).copied().collect();
+            prop_assert_eq!(values_of(&a.intersection(&b).into_iter().collect::<Vec<_>>()), intersection);
+
+            let union: BTreeSet<i32> = set_a.union(&set_b).copied().collect();
+            let contiguous = union.len() == (union.last().unwrap() - union.first().unwrap() + 1) as usize;
+            match a.union(&b) {
+                Some(joined) => prop_assert_eq!(values(&joined), union),
+                None => prop_assert!(!contiguous),
+            }
+
+            let difference: BTreeSet<i32> = set_a.difference(&set_b).copied().collect();
+            let pieces = a.difference(&b);
+            prop_assert!(pieces.windows(2).all(|pair| pair[0].end() + 1 < pair[1].start()));
+            prop_assert_eq!(values_of(&pieces), difference);
+        }
+    }
+}
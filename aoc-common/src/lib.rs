@@ -0,0 +1,29 @@
+#[cfg(feature = "bench")]
+pub mod bench;
+mod error;
+mod grid;
+mod input;
+mod interval;
+mod parse;
+mod solution;
+
+pub use error::{ParseError, SolveError};
+pub use grid::{Grid, DIRECTIONS4, DIRECTIONS8};
+pub use input::{
+    lines, non_empty_lines, parse_all_lines, parse_non_empty_lines, read_file, read_input,
+    read_input_arg, read_input_or_stdin, read_lines,
+};
+pub use interval::Interval;
+pub use parse::parse_num;
+pub use solution::{read_parsed, read_parsed_from, solve, Solution, Solved};
+
+// Grid coordinates. Most days work with signed coordinates, so default to `i32`.
+pub type Point<T = i32> = (T, T);
+pub type Point3<T = i32> = (T, T, T);
+
+// Which half of a day's puzzle to solve.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
@@ -0,0 +1,13 @@
+use crate::ParseError;
+use regex::Captures;
+use std::str::FromStr;
+
+// Parse a named capture group, matched on the given line, as a number.
+pub fn parse_num<T: FromStr>(caps: &Captures, label: &str, line: usize) -> Result<T, ParseError> {
+    let m = caps
+        .name(label)
+        .unwrap_or_else(|| panic!("Missing capture group {}", label));
+    m.as_str()
+        .parse::<T>()
+        .map_err(|_| ParseError::new(line, m.start() + 1, "a number", m.as_str()))
+}
@@ -0,0 +1,64 @@
+use crate::{read_input_or_stdin, ParseError, Part, SolveError};
+use std::env;
+use std::fmt::Display;
+use std::process;
+use std::time::{Duration, Instant};
+
+// A day's puzzle: how to parse the raw input, and how to solve each part from the parsed input.
+// Most parts always have an answer, but some inputs parse fine and still can't be solved.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError>;
+}
+
+// A day's answers to the parts that were asked for, or why they couldn't be answered, with how
+// long parsing and each part took.
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<(Part, Result<String, SolveError>, Duration)>,
+}
+
+// Parse an input once and solve each of `parts` from it, formatting the answers for display.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&parsed).map(|answer| answer.to_string()),
+                Part::Two => S::part2(&parsed).map(|answer| answer.to_string()),
+            };
+            (part, answer, start.elapsed())
+        })
+        .collect();
+
+    Ok(Solved {
+        parse_time,
+        answers,
+    })
+}
+
+// Read and parse the input given on the command line (see `read_input_arg`), exiting with an
+// annotated error if it is malformed.
+pub fn read_parsed<S: Solution>() -> S::Input {
+    read_parsed_from::<S>(env::args().nth(1).as_deref())
+}
+
+// Read and parse the named input file, or stdin if there isn't one, for days whose command lines
+// take more than a filename.
+pub fn read_parsed_from<S: Solution>(filename: Option<&str>) -> S::Input {
+    let (input, source_name) = read_input_or_stdin(filename);
+    S::parse(&input).unwrap_or_else(|e| {
+        eprint!("{}", e.annotate(&input, &source_name));
+        process::exit(1);
+    })
+}
@@ -0,0 +1,39 @@
+use aoc_common::{solve, ParseError, Part, Solved};
+
+// Parse an input and solve the given parts of a day's puzzle.
+type RunFn = fn(&str, &[Part]) -> Result<Solved, ParseError>;
+
+pub struct Day {
+    pub number: u32,
+    pub run: RunFn,
+}
+
+const fn day(number: u32, run: RunFn) -> Day {
+    Day { number, run }
+}
+
+pub const DAYS: [Day; 19] = [
+    day(1, solve::<day1::Day1>),
+    day(2, solve::<day2::Day2>),
+    day(3, solve::<day3::Day3>),
+    day(4, solve::<day4::Day4>),
+    day(5, solve::<day5::Day5>),
+    day(6, solve::<day6::Day6>),
+    day(7, solve::<day7::Day7>),
+    day(8, solve::<day8::Day8>),
+    day(9, solve::<day9::Day9>),
+    day(10, solve::<day10::Day10>),
+    day(11, solve::<day11::Day11>),
+    day(12, solve::<day12::Day12>),
+    day(13, solve::<day13::Day13>),
+    day(14, solve::<day14::Day14>),
+    day(15, solve::<day15::Day15>),
+    day(16, solve::<day16::Day16>),
+    day(17, solve::<day17::Day17>),
+    day(18, solve::<day18::Day18>),
+    day(19, solve::<day19::Day19>),
+];
+
+pub fn find_day(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,213 @@
+mod days;
+
+use crate::days::{find_day, Day, DAYS};
+use aoc_common::Part;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::json;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Run the Advent of Code 2022 solutions.
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day in sequence.
+    Run {
+        /// Day number, or `all` to run every day.
+        day: DaySelection,
+
+        /// Input file to solve, or `-` to read stdin. Defaults to `dayN/input` in the current
+        /// directory.
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Only solve the given part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// How to print the answers.
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// `Day N, part P: answer` lines.
+    Text,
+    /// One JSON object per day: `{"day": N, "part1": ..., "part2": ..., "elapsed_us": ...}`, with
+    /// `parse_us`, `part1_us` and `part2_us` breaking the time down.
+    Json,
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u32),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+
+        s.parse::<u32>()
+            .ok()
+            .filter(|&n| find_day(n).is_some())
+            .map(DaySelection::Day)
+            .ok_or_else(|| format!("expected `all` or a day from 1 to {}", DAYS.len()))
+    }
+}
+
+// Load a day's input, along with a name for where it came from to use in error messages.
+fn load_input(day: &Day, path: Option<&Path>) -> Result<(String, String), String> {
+    let default_path = PathBuf::from(format!("day{}/input", day.number));
+    let path = path.unwrap_or(&default_path);
+
+    if path == Path::new("-") {
+        let mut input = String::new();
+        return io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| (input, String::from("<stdin>")))
+            .map_err(|e| format!("Failed to read stdin: {}", e));
+    }
+
+    fs::read_to_string(path)
+        .map(|input| (input, path.display().to_string()))
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+// The answers to each part that was solved, and how long parsing and solving each part took.
+struct DayResult {
+    part1: Option<String>,
+    part2: Option<String>,
+    parse_time: Duration,
+    part1_time: Option<Duration>,
+    part2_time: Option<Duration>,
+}
+
+impl DayResult {
+    fn elapsed(&self) -> Duration {
+        self.parse_time + self.part1_time.unwrap_or_default() + self.part2_time.unwrap_or_default()
+    }
+}
+
+fn run_day(day: &Day, path: Option<&Path>, parts: &[Part]) -> Result<DayResult, String> {
+    let (input, source_name) = load_input(day, path)?;
+    let solved = (day.run)(&input, parts)
+        .map_err(|e| e.annotate(&input, &source_name).trim_end().to_string())?;
+
+    let mut result = DayResult {
+        part1: None,
+        part2: None,
+        parse_time: solved.parse_time,
+        part1_time: None,
+        part2_time: None,
+    };
+    for (part, answer, elapsed) in solved.answers {
+        // A part that can't be answered fails the whole day, like input that doesn't parse.
+        let answer = answer.map_err(|e| {
+            let number = if part == Part::One { 1 } else { 2 };
+            format!("error: couldn't solve part {}: {}", number, e)
+        })?;
+        match part {
+            Part::One => (result.part1, result.part1_time) = (Some(answer), Some(elapsed)),
+            Part::Two => (result.part2, result.part2_time) = (Some(answer), Some(elapsed)),
+        }
+    }
+
+    Ok(result)
+}
+
+// Numeric answers are emitted as JSON numbers, anything else (e.g. day 5's crate letters) as a
+// string.
+fn json_answer(answer: &str) -> serde_json::Value {
+    match answer.parse::<i64>() {
+        Ok(number) => json!(number),
+        Err(_) => json!(answer),
+    }
+}
+
+fn print_result(day: &Day, result: &DayResult, format: Format) {
+    match format {
+        Format::Text => {
+            let answers = [(1, &result.part1), (2, &result.part2)];
+            for (part_number, answer) in answers {
+                let Some(answer) = answer else { continue };
+
+                // Some answers (e.g. day 10's screen) span multiple lines, start those on a new line.
+                let separator = if answer.contains('\n') { "\n" } else { " " };
+                println!(
+                    "Day {}, part {}:{}{}",
+                    day.number, part_number, separator, answer
+                );
+            }
+        }
+        Format::Json => println!(
+            "{}",
+            json!({
+                "day": day.number,
+                "part1": result.part1.as_deref().map(json_answer),
+                "part2": result.part2.as_deref().map(json_answer),
+                "elapsed_us": result.elapsed().as_micros() as u64,
+                "parse_us": result.parse_time.as_micros() as u64,
+                "part1_us": result.part1_time.map(|t| t.as_micros() as u64),
+                "part2_us": result.part2_time.map(|t| t.as_micros() as u64),
+            })
+        ),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let Command::Run {
+        day,
+        input,
+        part,
+        format,
+    } = cli.command;
+    let parts = match part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let selected: Vec<&Day> = match day {
+        DaySelection::All if input.is_some() => {
+            eprintln!("--input can only be used when running a single day");
+            return ExitCode::FAILURE;
+        }
+        DaySelection::All => DAYS.iter().collect(),
+        DaySelection::Day(n) => vec![find_day(n).expect("Day was validated when parsing")],
+    };
+
+    let mut failed = false;
+    for day in selected {
+        match run_day(day, input.as_deref(), &parts) {
+            Ok(result) => print_result(day, &result, format),
+            Err(e) => {
+                eprintln!("Day {}: {}", day.number, e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
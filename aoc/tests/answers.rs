@@ -0,0 +1,103 @@
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner is inside the workspace")
+        .to_path_buf()
+}
+
+// Every day with an `answers.toml`, in day order.
+fn days_with_answers(root: &Path) -> Vec<(u32, toml::Table)> {
+    let mut days: Vec<(u32, toml::Table)> = fs::read_dir(root)
+        .expect("Failed to read workspace")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let number = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("day")?
+                .parse()
+                .ok()?;
+            let answers = fs::read_to_string(path.join("answers.toml")).ok()?;
+            let answers = answers
+                .parse()
+                .unwrap_or_else(|e| panic!("Failed to parse day{}/answers.toml: {}", number, e));
+            Some((number, answers))
+        })
+        .collect();
+
+    days.sort_by_key(|(number, _)| *number);
+    days
+}
+
+// Answers are compared as text, as the runner emits numbers for numeric answers but
+// `answers.toml` is free to quote them.
+fn expected_text(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn actual_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[test]
+fn real_input_answers() {
+    let root = workspace_root();
+    let days = days_with_answers(&root);
+    assert!(!days.is_empty(), "No answers.toml files found");
+
+    // Run every day at once, the slow days take seconds each.
+    let runs: Vec<_> = days
+        .iter()
+        .map(|(number, _)| {
+            Command::new(env!("CARGO_BIN_EXE_aoc"))
+                .args(["run", &number.to_string(), "--format", "json"])
+                .current_dir(&root)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .expect("Failed to start the runner")
+        })
+        .collect();
+
+    let mut mismatches = Vec::new();
+    for ((number, answers), run) in days.iter().zip(runs) {
+        let output = run.wait_with_output().expect("Failed to run the runner");
+        if !output.status.success() {
+            mismatches.push(format!(
+                "Day {} failed:\n{}",
+                number,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+            continue;
+        }
+
+        let result: Value =
+            serde_json::from_slice(&output.stdout).expect("The runner printed invalid JSON");
+        for part in ["part1", "part2"] {
+            let Some(expected) = answers.get(part) else {
+                continue;
+            };
+            let expected = expected_text(expected);
+            let actual = actual_text(&result[part]);
+            if expected != actual {
+                mismatches.push(format!(
+                    "Day {}, {}: expected\n{}\nbut got\n{}",
+                    number, part, expected, actual
+                ));
+            }
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n\n"));
+}
@@ -0,0 +1,3 @@
+use day1::Day1;
+
+aoc_common::bench_main!(Day1, ["input"]);
@@ -0,0 +1,407 @@
+use aoc_common::ParseError;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+// One elf's group of items, in the order the elves appear in the inventory, from 0. Groups can
+// be named by a `# name` header line, and `source` indexes the inventory's sources.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Elf {
+    pub index: usize,
+    pub name: Option<String>,
+    pub source: usize,
+    pub items: usize,
+    pub calories: i64,
+}
+
+// A group being read: its name, if it had a header, and its item count and calories so far.
+#[derive(Default)]
+struct Group {
+    name: Option<String>,
+    items: usize,
+    calories: i64,
+}
+
+// Reading an inventory can fail either reading the input, or on a malformed line.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ReadError::Io(e) => write!(f, "failed to read inventory: {}", e),
+            ReadError::Parse(e) => write!(f, "{}", e),
+        };
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        return ReadError::Io(e);
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        return ReadError::Parse(e);
+    }
+}
+
+// Every elf's items, merged from one or more sources, along with the `top_k` elves carrying the
+// most calories. Only the top elves are kept in order, in a min-heap, so adding an elf is
+// O(log K) rather than sorting them all.
+#[derive(Clone, Debug)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+    sources: Vec<String>,
+    top_k: usize,
+
+    // Calories and index of the top elves, smallest first. On a tie the earlier elf ranks higher.
+    top: BinaryHeap<Reverse<(i64, Reverse<usize>)>>,
+}
+
+impl Inventory {
+    pub fn new(top_k: usize) -> Self {
+        return Inventory {
+            elves: Vec::new(),
+            sources: Vec::new(),
+            top_k,
+            top: BinaryHeap::with_capacity(top_k + 1),
+        };
+    }
+
+    // Read a single unnamed source, see `read_source`.
+    pub fn read<R: BufRead>(reader: R, top_k: usize) -> Result<Self, ReadError> {
+        let mut inventory = Inventory::new(top_k);
+        inventory.read_source(reader, "<input>")?;
+        return Ok(inventory);
+    }
+
+    // Stream another source into the inventory a line at a time, with each elf's calorie counts
+    // on their own lines and a blank line between elves. The last elf doesn't need a blank line
+    // after it. A `# name` line names the group after it, and also ends any group before it.
+    // Errors are reported against lines of this source.
+    pub fn read_source<R: BufRead>(
+        &mut self,
+        reader: R,
+        source_name: &str,
+    ) -> Result<(), ReadError> {
+        let source = self.sources.len();
+        self.sources.push(String::from(source_name));
+        let mut group: Option<Group> = None;
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let trimmed = line.trim();
+            let indent = line[..line.len() - line.trim_start().len()].chars().count();
+
+            if trimmed.is_empty() {
+                // Runs of blank lines only separate one pair of elves, and a header can be
+                // separated from its group.
+                if group.as_ref().is_some_and(|group| group.items > 0) {
+                    self.add_group(group.take().unwrap(), source);
+                }
+                continue;
+            }
+
+            if let Some(name) = trimmed.strip_prefix('#') {
+                let name = name.trim();
+                if name.is_empty() {
+                    let mut e = ParseError::bad_line(index + 1, trimmed, "an elf name");
+                    e.column += indent;
+                    return Err(e.into());
+                }
+
+                // A header straight after another one names an elf carrying nothing.
+                if let Some(previous) = group.take() {
+                    self.add_group(previous, source);
+                }
+                group = Some(Group {
+                    name: Some(String::from(name)),
+                    ..Group::default()
+                });
+                continue;
+            }
+
+            let calories = trimmed.parse::<i64>().map_err(|_| {
+                let mut e = ParseError::bad_line(index + 1, trimmed, "a calorie count");
+                e.column += indent;
+                return e;
+            })?;
+            let current = group.get_or_insert_with(Group::default);
+            current.items += 1;
+            current.calories += calories;
+        }
+
+        if let Some(group) = group {
+            self.add_group(group, source);
+        }
+
+        return Ok(());
+    }
+
+    fn add_group(&mut self, group: Group, source: usize) {
+        self.add_elf(group.name, source, group.items, group.calories);
+    }
+
+    // Add the next elf, carrying `items` items worth `calories` in total, from the source with
+    // index `source`.
+    pub fn add_elf(&mut self, name: Option<String>, source: usize, items: usize, calories: i64) {
+        let index = self.elves.len();
+        self.elves.push(Elf {
+            index,
+            name,
+            source,
+            items,
+            calories,
+        });
+
+        self.top.push(Reverse((calories, Reverse(index))));
+        if self.top.len() > self.top_k {
+            self.top.pop();
+        }
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        return &self.elves;
+    }
+
+    // The name of the source an elf was read from.
+    pub fn source_name(&self, elf: &Elf) -> &str {
+        return &self.sources[elf.source];
+    }
+
+    // Every name given to more than one elf, in name order, with the elves that share it.
+    pub fn duplicate_names(&self) -> Vec<(&str, Vec<&Elf>)> {
+        let mut by_name: BTreeMap<&str, Vec<&Elf>> = BTreeMap::new();
+        for elf in &self.elves {
+            if let Some(name) = &elf.name {
+                by_name.entry(name).or_default().push(elf);
+            }
+        }
+
+        return by_name
+            .into_iter()
+            .filter(|(_, elves)| elves.len() > 1)
+            .collect();
+    }
+
+    // The top elves, most calories first, with up to `top_k` of them.
+    pub fn top(&self) -> Vec<&Elf> {
+        let mut top: Vec<(i64, Reverse<usize>)> = self.top.iter().map(|entry| entry.0).collect();
+        top.sort_by(|a, b| b.cmp(a));
+        return top
+            .into_iter()
+            .map(|(_, Reverse(index))| &self.elves[index])
+            .collect();
+    }
+
+    // The total calories carried by the top `n` elves, which can't be more than `top_k`.
+    pub fn top_total(&self, n: usize) -> i64 {
+        assert!(
+            n <= self.top_k,
+            "Only the top {} elves are kept",
+            self.top_k
+        );
+        return self.top().iter().take(n).map(|elf| elf.calories).sum();
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.elves.is_empty() {
+            return None;
+        }
+        let total: i64 = self.elves.iter().map(|elf| elf.calories).sum();
+        return Some(total as f64 / self.elves.len() as f64);
+    }
+
+    // The median of the elves' calories, halfway between the middle two for an even count.
+    pub fn median(&self) -> Option<f64> {
+        if self.elves.is_empty() {
+            return None;
+        }
+        let mut calories: Vec<i64> = self.elves.iter().map(|elf| elf.calories).collect();
+        let middle = calories.len() / 2;
+        let odd = calories.len() % 2 == 1;
+        let (lower, &mut upper, _) = calories.select_nth_unstable(middle);
+        if odd {
+            return Some(upper as f64);
+        }
+        let below = *lower.iter().max().unwrap();
+        return Some((below + upper) as f64 / 2.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Render each elf's items as the puzzle does, a line per item with a blank line between elves.
+    fn render(elves: &[Vec<i64>], trailing_blank: bool) -> String {
+        let groups: Vec<String> = elves
+            .iter()
+            .map(|items| {
+                let lines: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+                return lines.join("\n");
+            })
+            .collect();
+        let mut input = groups.join("\n\n");
+        if trailing_blank {
+            input.push_str("\n\n");
+        }
+        return input;
+    }
+
+    fn example() -> Inventory {
+        let elves = [
+            vec![1000, 2000, 3000],
+            vec![4000],
+            vec![5000, 6000],
+            vec![7000, 8000, 9000],
+            vec![10000],
+        ];
+        return Inventory::read(render(&elves, false).as_bytes(), 3).unwrap();
+    }
+
+    #[test]
+    fn top_test() {
+        let inventory = example();
+        let top: Vec<(usize, i64)> = inventory
+            .top()
+            .iter()
+            .map(|elf| (elf.index, elf.calories))
+            .collect();
+        assert_eq!(top, vec![(3, 24000), (2, 11000), (4, 10000)]);
+        assert_eq!(inventory.top_total(3), 45000);
+    }
+
+    #[test]
+    fn stats_test() {
+        let inventory = example();
+        let items: Vec<usize> = inventory.elves().iter().map(|elf| elf.items).collect();
+        assert_eq!(items, vec![3, 1, 2, 3, 1]);
+        assert_eq!(inventory.mean(), Some(11000.0));
+        assert_eq!(inventory.median(), Some(10000.0));
+
+        let empty = Inventory::read("\n\n".as_bytes(), 3).unwrap();
+        assert!(empty.elves().is_empty());
+        assert_eq!(empty.median(), None);
+    }
+
+    #[test]
+    fn separators_test() {
+        // Extra blank lines don't make empty elves, and the last elf needs no blank line after it.
+        let inventory = Inventory::read("\n1\n2\n\n\n\n3\n".as_bytes(), 2).unwrap();
+        let totals: Vec<i64> = inventory.elves().iter().map(|elf| elf.calories).collect();
+        assert_eq!(totals, vec![3, 3]);
+        assert_eq!(inventory.median(), Some(3.0));
+
+        // Ties keep the earlier elf.
+        let top: Vec<usize> = Inventory::read("5\n\n5\n\n5".as_bytes(), 2)
+            .unwrap()
+            .top()
+            .iter()
+            .map(|elf| elf.index)
+            .collect();
+        assert_eq!(top, vec![0, 1]);
+    }
+
+    #[test]
+    fn names_test() {
+        let mut inventory = Inventory::new(2);
+        let first = "# alice\n100\n200\n\n300\n# bob\n\n400\n# carol\n";
+        inventory
+            .read_source(first.as_bytes(), "north.txt")
+            .unwrap();
+        inventory
+            .read_source("# alice\n500".as_bytes(), "south.txt")
+            .unwrap();
+
+        let elves: Vec<(Option<&str>, &str, usize, i64)> = inventory
+            .elves()
+            .iter()
+            .map(|elf| {
+                let source = inventory.source_name(elf);
+                return (elf.name.as_deref(), source, elf.items, elf.calories);
+            })
+            .collect();
+        assert_eq!(
+            elves,
+            vec![
+                (Some("alice"), "north.txt", 2, 300),
+                (None, "north.txt", 1, 300),
+                (Some("bob"), "north.txt", 1, 400),
+                (Some("carol"), "north.txt", 0, 0),
+                (Some("alice"), "south.txt", 1, 500),
+            ]
+        );
+
+        let top: Vec<usize> = inventory.top().iter().map(|elf| elf.index).collect();
+        assert_eq!(top, vec![4, 2]);
+
+        let duplicates = inventory.duplicate_names();
+        assert_eq!(duplicates.len(), 1);
+        let (name, elves) = &duplicates[0];
+        assert_eq!(*name, "alice");
+        assert_eq!(
+            elves.iter().map(|elf| elf.index).collect::<Vec<_>>(),
+            vec![0, 4]
+        );
+    }
+
+    #[test]
+    fn name_error_test() {
+        let err = Inventory::read("100\n\n  #\n".as_bytes(), 3).unwrap_err();
+        let ReadError::Parse(err) = err else {
+            panic!("Expected a parse error, got {}", err);
+        };
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.expected, "an elf name");
+    }
+
+    proptest! {
+        #[test]
+        fn matches_sorted_totals(
+            elves in prop::collection::vec(prop::collection::vec(1..100_000i64, 1..10), 1..50),
+            trailing_blank: bool,
+            top_k in 1..6usize,
+        ) {
+            let input = render(&elves, trailing_blank);
+            let inventory = Inventory::read(input.as_bytes(), top_k).unwrap();
+            let totals: Vec<i64> = elves.iter().map(|items| items.iter().sum()).collect();
+
+            let parsed: Vec<(usize, i64)> =
+                inventory.elves().iter().map(|elf| (elf.items, elf.calories)).collect();
+            let expected: Vec<(usize, i64)> =
+                elves.iter().zip(&totals).map(|(items, &total)| (items.len(), total)).collect();
+            prop_assert_eq!(parsed, expected);
+
+            // A stable sort keeps tied elves in input order.
+            let mut ranked: Vec<(usize, i64)> = totals.iter().copied().enumerate().collect();
+            ranked.sort_by_key(|&(_, total)| Reverse(total));
+            ranked.truncate(top_k);
+            let top: Vec<(usize, i64)> =
+                inventory.top().iter().map(|elf| (elf.index, elf.calories)).collect();
+            prop_assert_eq!(top, ranked);
+
+            let mut sorted = totals.clone();
+            sorted.sort();
+            let n = sorted.len();
+            let median = if n % 2 == 1 {
+                sorted[n / 2] as f64
+            } else {
+                (sorted[n / 2 - 1] + sorted[n / 2]) as f64 / 2.0
+            };
+            prop_assert_eq!(inventory.median(), Some(median));
+            let mean = inventory.mean().unwrap();
+            prop_assert!((mean * n as f64 - totals.iter().sum::<i64>() as f64).abs() < 1e-3);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+pub mod inventory;
+
+use crate::inventory::{Inventory, ReadError};
+use aoc_common::{ParseError, Solution, SolveError};
+
+// Both parts only need the top three elves, so that is how many are kept by default.
+pub const TOP_K: usize = 3;
+
+pub fn part1(inventory: &Inventory) -> i64 {
+    return inventory.top_total(1);
+}
+
+pub fn part2(inventory: &Inventory) -> i64 {
+    return inventory.top_total(3);
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Inventory;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return match Inventory::read(input.as_bytes(), TOP_K) {
+            Ok(inventory) => Ok(inventory),
+            Err(ReadError::Parse(e)) => Err(e),
+            Err(ReadError::Io(e)) => panic!("Failed to read input from memory: {}", e),
+        };
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_test() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+        let inventory = Day1::parse(input).unwrap();
+        assert_eq!(part1(&inventory), 24000);
+        assert_eq!(part2(&inventory), 45000);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = Day1::parse("1000\n\n  20x0\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.found, "20x0");
+    }
+}
@@ -1,23 +1,109 @@
+use day1::inventory::{Inventory, ReadError};
+use day1::{part1, part2, TOP_K};
+use std::env;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufReader};
+use std::process;
 
-fn main() {
-    let file = File::open("input").unwrap();
-    let reader = BufReader::new(file);
-
-    let mut elves = Vec::new();
-    let mut cals = 0;
-
-    for line in reader.lines() {
-        let line = line.unwrap();
-        if line.is_empty() {
-            elves.push(cals);
-            cals = 0;
-        } else {
-            cals += line.parse::<i64>().unwrap();
+const USAGE: &str = "Usage: day1 [INPUT...] [--top=K]";
+
+struct Options {
+    filenames: Vec<String>,
+    top_k: usize,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        filenames: Vec::new(),
+        top_k: TOP_K,
+    };
+
+    for arg in env::args().skip(1) {
+        let (option, value) = arg.split_once('=').unwrap_or((&arg, ""));
+        match option {
+            "--top" => {
+                options.top_k = value
+                    .parse()
+                    .map_err(|_| format!("Bad number of elves `{}`", value))?;
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unexpected argument `{}`", arg)),
+            _ => options.filenames.push(arg),
+        }
+    }
+    return Ok(options);
+}
+
+// Stream every inventory file named on the command line into one inventory, or read one from
+// stdin if there aren't any, exiting with an error if one can't be read. Both parts need the top
+// three elves, so at least that many are kept.
+fn read_inventory(options: &Options) -> Inventory {
+    let mut inventory = Inventory::new(options.top_k.max(TOP_K));
+    let fail = |e: ReadError, source_name: &str| -> ! {
+        match e {
+            // The input was streamed, so there's no line to show along with the error.
+            ReadError::Parse(e) => eprint!("{}", e.annotate("", source_name)),
+            ReadError::Io(e) => eprintln!("Failed to read {}: {}", source_name, e),
+        }
+        process::exit(1);
+    };
+
+    if options.filenames.is_empty() {
+        let source_name = "<stdin>";
+        if let Err(e) = inventory.read_source(io::stdin().lock(), source_name) {
+            fail(e, source_name);
         }
     }
-    elves.sort_by(|a, b| b.cmp(a));
-        
-    println!("Part 1: {}, Part 2: {}", elves[0], elves[0] + elves[1] + elves[2]);
+    for filename in &options.filenames {
+        let file = File::open(filename).unwrap_or_else(|e| {
+            eprintln!("Failed to open {}: {}", filename, e);
+            process::exit(1);
+        });
+        if let Err(e) = inventory.read_source(BufReader::new(file), filename) {
+            fail(e, filename);
+        }
+    }
+    return inventory;
+}
+
+fn main() {
+    let options = parse_options().unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    });
+    let inventory = read_inventory(&options);
+
+    println!(
+        "Part 1: {}, Part 2: {}",
+        part1(&inventory),
+        part2(&inventory)
+    );
+
+    let top = inventory.top();
+    for (slot, elf) in top.iter().take(options.top_k).enumerate() {
+        let name = match &elf.name {
+            Some(name) => name.clone(),
+            None => format!("elf {}", elf.index),
+        };
+        println!(
+            "#{}: {} ({}) carries {} calories in {} items",
+            slot + 1,
+            name,
+            inventory.source_name(elf),
+            elf.calories,
+            elf.items
+        );
+    }
+    if let (Some(mean), Some(median)) = (inventory.mean(), inventory.median()) {
+        println!("Mean: {:.1}, Median: {:.1}", mean, median);
+    }
+
+    for (name, elves) in inventory.duplicate_names() {
+        let sources: Vec<&str> = elves.iter().map(|elf| inventory.source_name(elf)).collect();
+        eprintln!(
+            "warning: {} elves are named {}, in {}",
+            elves.len(),
+            name,
+            sources.join(", ")
+        );
+    }
 }
@@ -0,0 +1,3 @@
+use day10::Day10;
+
+aoc_common::bench_main!(Day10, ["input", "test_input"]);
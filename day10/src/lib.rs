@@ -0,0 +1,233 @@
+use aoc_common::{parse_non_empty_lines, ParseError, Solution, SolveError};
+use std::fmt;
+
+#[derive(Copy, Clone, Debug)]
+pub enum Operation {
+    AddX(i32),
+    Noop,
+}
+
+fn parse_op(line_number: usize, line: &String) -> Result<Operation, ParseError> {
+    let mut parts = line.split(' ');
+    return match parts.next() {
+        Some("addx") => {
+            let val = parts
+                .next()
+                .and_then(|v| v.parse::<i32>().ok())
+                .ok_or_else(|| {
+                    ParseError::in_line(line_number, line, line.len().min(5), "a value")
+                })?;
+            Ok(Operation::AddX(val))
+        }
+        Some("noop") => Ok(Operation::Noop),
+        _ => Err(ParseError::in_line(
+            line_number,
+            line,
+            0,
+            "`addx` or `noop`",
+        )),
+    };
+}
+
+pub fn parse_lines(lines: &Vec<String>) -> Result<Vec<Operation>, ParseError> {
+    return lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| parse_op(index + 1, line))
+        .collect();
+}
+
+fn pipeline_wait(op: Operation) -> u32 {
+    return match op {
+        Operation::AddX(_) => 2,
+        Operation::Noop => 1,
+    };
+}
+
+fn execute(ops: &Vec<Operation>) -> [i32; 240] {
+    let mut x: i32 = 1;
+
+    let mut current_op = None;
+    let mut wait = 0;
+    let mut cycles = 0;
+    let mut op_index = 0;
+
+    let mut output: [i32; 240] = [0; 240];
+
+    loop {
+        cycles += 1;
+
+        // Start of the cycle - start a new op if required.
+        if current_op.is_none() && op_index < ops.len() {
+            let new_op = ops[op_index];
+            op_index += 1;
+            wait = pipeline_wait(new_op);
+            current_op = Some(new_op);
+        }
+
+        // Middle of the cycle.
+        output[cycles - 1] = x;
+        if cycles == 240 {
+            return output;
+        }
+
+        // End of the cycle, finalize any op that has finished.
+        if let Some(op) = current_op {
+            wait -= 1;
+
+            if wait == 0 {
+                match op {
+                    Operation::AddX(val) => x += val,
+                    Operation::Noop => (),
+                }
+                current_op = None;
+            }
+        }
+    }
+}
+
+fn build_image(sprite_pos: [i32; 240]) -> [char; 240] {
+    let mut output = ['.'; 240];
+
+    for scan in 0..240 {
+        let sprite_center = sprite_pos[scan];
+        let sprite_range = (sprite_center - 1)..=(sprite_center + 1);
+        let current_col = (scan % 40) as i32;
+
+        if sprite_range.contains(&current_col) {
+            output[scan] = '#';
+        }
+    }
+
+    return output;
+}
+
+// The CRT output, displayed as rows of 40 pixels.
+pub struct Screen(pub [char; 240]);
+
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<String> = self
+            .0
+            .chunks(40)
+            .map(|chars| chars.iter().collect())
+            .collect();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+pub fn print_screen(screen: [char; 240]) {
+    println!("{}", Screen(screen));
+}
+
+pub fn part1(ops: &Vec<Operation>) -> i32 {
+    let output = execute(ops);
+    let mut result = 0;
+
+    for i in (20..=240).step_by(40) {
+        result += i as i32 * output[i - 1];
+    }
+
+    return result;
+}
+
+pub fn part2(ops: &Vec<Operation>) -> [char; 240] {
+    let sprite_pos = execute(ops);
+    return build_image(sprite_pos);
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Operation>;
+    type Answer1 = i32;
+    type Answer2 = Screen;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(Screen(part2(input)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn program() -> impl Strategy<Value = Vec<String>> {
+        let op = prop_oneof![
+            Just(String::from("noop")),
+            (-5..=5i32).prop_map(|val| format!("addx {}", val)),
+        ];
+        return prop::collection::vec(op, 0..200);
+    }
+
+    // The value of X during each cycle, found by expanding every op into the cycles it takes.
+    fn cycle_values(ops: &Vec<Operation>) -> Vec<i32> {
+        let mut x = 1;
+        let mut values = Vec::new();
+        for op in ops {
+            match op {
+                Operation::Noop => values.push(x),
+                Operation::AddX(val) => {
+                    values.extend([x, x]);
+                    x += val;
+                }
+            }
+        }
+        values.resize(values.len().max(240), x);
+        return values;
+    }
+    use aoc_common::read_file;
+
+    #[test]
+    fn pt1_test() {
+        let lines = read_file("test_input");
+        let ops = parse_lines(&lines).unwrap();
+        let result = part1(&ops);
+
+        assert_eq!(result, 13140);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = read_file("test_input");
+        let ops = parse_lines(&lines).unwrap();
+        let result = part2(&ops);
+
+        let expected = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ];
+        assert_eq!(Screen(result).to_string(), expected.join("\n"));
+    }
+
+    proptest! {
+        #[test]
+        fn parts_match_expanded_cycles(lines in program()) {
+            let ops = parse_lines(&lines).unwrap();
+            let values = cycle_values(&ops);
+
+            let strength: i32 = (20..=220).step_by(40).map(|cycle| cycle as i32 * values[cycle - 1]).sum();
+            prop_assert_eq!(part1(&ops), strength);
+
+            let screen = part2(&ops);
+            for (pixel, &lit) in screen.iter().enumerate() {
+                let col = (pixel % 40) as i32;
+                let expected = if (values[pixel] - col).abs() <= 1 { '#' } else { '.' };
+                prop_assert_eq!(lit, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+use day11::Day11;
+
+aoc_common::bench_main!(Day11, ["input"]);
@@ -0,0 +1,438 @@
+use aoc_common::{parse_non_empty_lines, parse_num, ParseError, Solution, SolveError};
+use num::integer::lcm;
+use regex::Regex;
+use std::collections::VecDeque;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    // The new worry level, or `None` if it doesn't fit in a `u64`.
+    fn apply(&self, old: u64) -> Option<u64> {
+        match self {
+            Operation::Add(n) => old.checked_add(*n),
+            Operation::Multiply(n) => old.checked_mul(*n),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    mod_value: u64,
+    on_true: usize,
+    on_false: usize,
+}
+
+// Where a worry level got too big to track, so there's no level of monkey business to give.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Overflow {
+    pub round: usize,
+    pub monkey: usize,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "monkey {}'s worry level overflowed in round {}",
+            self.monkey, self.round
+        )
+    }
+}
+
+fn monkey_business(
+    monkeys: &mut Vec<Monkey>,
+    rounds: usize,
+    worry_reduction: u64,
+) -> Result<u64, Overflow> {
+    // In order to keep the numbers in range for part 2, we can take the modulo of each item
+    // with the LCM of all the monkey's check modulo values - this doesn't affect the result
+    // of each move. That only holds without the worry reduction, as dividing a reduced value
+    // doesn't give the same remainders, so part 1's values aren't reduced and can overflow.
+    let mod_multiple: u64 = monkeys
+        .iter()
+        .fold(1, |acc, monkey| lcm(acc, monkey.mod_value));
+
+    let mut counts = vec![0; monkeys.len()];
+    for round in 1..=rounds {
+        for i in 0..monkeys.len() {
+            while let Some(mut item) = monkeys[i].items.pop_front() {
+                counts[i] += 1;
+
+                // Without a reduction, part 1's levels are only kept small by the division.
+                let Some(new_item) = monkeys[i].operation.apply(item) else {
+                    return Err(Overflow { round, monkey: i });
+                };
+                item = new_item / worry_reduction;
+                if worry_reduction == 1 {
+                    item %= mod_multiple;
+                }
+
+                let throw_at = if item % monkeys[i].mod_value == 0 {
+                    monkeys[i].on_true
+                } else {
+                    monkeys[i].on_false
+                };
+
+                monkeys[throw_at].items.push_back(item);
+            }
+        }
+    }
+
+    counts.sort_by(|a, b| b.cmp(a));
+    return Ok(counts.iter().take(2).product());
+}
+
+pub fn part1(mut monkeys: Vec<Monkey>) -> Result<u64, Overflow> {
+    return monkey_business(&mut monkeys, 20, 3);
+}
+
+pub fn part2(mut monkeys: Vec<Monkey>) -> Result<u64, Overflow> {
+    return monkey_business(&mut monkeys, 10000, 1);
+}
+
+// Each monkey's notes take up this many non-blank lines.
+const NOTE_LINES: usize = 6;
+
+pub fn parse_lines(lines: &Vec<String>) -> Result<Vec<Monkey>, ParseError> {
+    let patterns = [
+        (r"^Monkey (?P<number>\d+):$", "`Monkey N:`"),
+        (
+            r"^Starting items: (?P<items>(\d+(, \d+)*)?)$",
+            "`Starting items: A, B, ...`",
+        ),
+        (
+            r"^Operation: new = old (?P<op>[*+]) (?P<operand>old|\d+)$",
+            "`Operation: new = old * N` or `Operation: new = old + N`",
+        ),
+        (
+            r"^Test: divisible by (?P<divisor>\d+)$",
+            "`Test: divisible by N`",
+        ),
+        (
+            r"^If true: throw to monkey (?P<target>\d+)$",
+            "`If true: throw to monkey N`",
+        ),
+        (
+            r"^If false: throw to monkey (?P<target>\d+)$",
+            "`If false: throw to monkey N`",
+        ),
+    ]
+    .map(|(pattern, expected)| {
+        (
+            Regex::new(pattern).expect("Failed to build regex"),
+            expected,
+        )
+    });
+
+    let monkey_count = lines.len().div_ceil(NOTE_LINES);
+    let mut monkeys = Vec::new();
+
+    for (number, notes) in lines.chunks(NOTE_LINES).enumerate() {
+        let first_line = number * NOTE_LINES + 1;
+        let mut caps = Vec::new();
+
+        for (i, (re, expected)) in patterns.iter().enumerate() {
+            let Some(line) = notes.get(i) else {
+                // The notes stop part way through this monkey.
+                let last = &lines[lines.len() - 1];
+                return Err(ParseError::in_line(
+                    lines.len(),
+                    last,
+                    last.len(),
+                    *expected,
+                ));
+            };
+            let line_caps = re
+                .captures(line)
+                .ok_or_else(|| ParseError::bad_line(first_line + i, line, *expected))?;
+            caps.push(line_caps);
+        }
+
+        // An error pointing at a number that was matched, but doesn't make sense.
+        let bad_number = |i: usize, label: &str, expected: &str| {
+            let m = caps[i].name(label).unwrap();
+            ParseError::new(first_line + i, m.start() + 1, expected, m.as_str())
+        };
+
+        // Monkeys are listed in order.
+        if parse_num::<usize>(&caps[0], "number", first_line)? != number {
+            return Err(bad_number(0, "number", &format!("monkey {}", number)));
+        }
+
+        let items_match = caps[1].name("items").unwrap();
+        let mut items = VecDeque::new();
+        let mut offset = items_match.start();
+        for item in items_match.as_str().split(", ").filter(|i| !i.is_empty()) {
+            let worry = item.parse::<u64>().map_err(|_| {
+                ParseError::in_line(first_line + 1, &notes[1], offset, "a worry level")
+            })?;
+            items.push_back(worry);
+            offset += item.len() + ", ".len();
+        }
+
+        let operation = match (&caps[2]["op"], &caps[2]["operand"]) {
+            ("*", "old") => Operation::Square,
+            ("+", "old") => Operation::Multiply(2),
+            ("*", _) => Operation::Multiply(parse_num(&caps[2], "operand", first_line + 2)?),
+            _ => Operation::Add(parse_num(&caps[2], "operand", first_line + 2)?),
+        };
+
+        let mod_value = parse_num(&caps[3], "divisor", first_line + 3)?;
+        if mod_value == 0 {
+            return Err(bad_number(3, "divisor", "a non-zero divisor"));
+        }
+
+        // Throwing an item to itself would leave a monkey throwing forever.
+        let mut targets = [0; 2];
+        for (target, i) in targets.iter_mut().zip([4, 5]) {
+            *target = parse_num(&caps[i], "target", first_line + i)?;
+            if *target >= monkey_count || *target == number {
+                return Err(bad_number(i, "target", "another monkey's number"));
+            }
+        }
+
+        monkeys.push(Monkey {
+            items,
+            operation,
+            mod_value,
+            on_true: targets[0],
+            on_false: targets[1],
+        });
+    }
+
+    return Ok(monkeys);
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return part1(input.clone()).map_err(|e| SolveError::new(e.to_string()));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return part2(input.clone()).map_err(|e| SolveError::new(e.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::read_file;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+
+    fn operation(squares: bool) -> BoxedStrategy<Operation> {
+        let add = (0..20u64).prop_map(Operation::Add);
+        let multiply = (1..20u64).prop_map(Operation::Multiply);
+        if squares {
+            return prop_oneof![add, multiply, Just(Operation::Square)].boxed();
+        }
+        return prop_oneof![add, multiply].boxed();
+    }
+
+    // A group of monkeys that only throw to other monkeys in the group.
+    fn monkeys(squares: bool) -> impl Strategy<Value = Vec<Monkey>> {
+        return (2..6usize).prop_flat_map(move |count| {
+            let monkey = (
+                prop::collection::vec(0..100u64, 0..5),
+                operation(squares),
+                2..20u64,
+                any::<(Index, Index)>(),
+            );
+            return prop::collection::vec(monkey, count).prop_map(move |specs| {
+                specs
+                    .into_iter()
+                    .enumerate()
+                    .map(
+                        |(number, (items, operation, mod_value, (on_true, on_false)))| {
+                            let other = |i: Index| (number + 1 + i.index(count - 1)) % count;
+                            return Monkey {
+                                items: items.into(),
+                                operation,
+                                mod_value,
+                                on_true: other(on_true),
+                                on_false: other(on_false),
+                            };
+                        },
+                    )
+                    .collect()
+            });
+        });
+    }
+
+    fn render(monkeys: &[Monkey]) -> Vec<String> {
+        let mut lines = Vec::new();
+        for (number, monkey) in monkeys.iter().enumerate() {
+            let items: Vec<String> = monkey.items.iter().map(|item| item.to_string()).collect();
+            let operation = match monkey.operation {
+                Operation::Add(n) => format!("+ {}", n),
+                Operation::Multiply(n) => format!("* {}", n),
+                Operation::Square => String::from("* old"),
+            };
+            lines.extend([
+                format!("Monkey {}:", number),
+                format!("Starting items: {}", items.join(", ")),
+                format!("Operation: new = old {}", operation),
+                format!("Test: divisible by {}", monkey.mod_value),
+                format!("If true: throw to monkey {}", monkey.on_true),
+                format!("If false: throw to monkey {}", monkey.on_false),
+            ]);
+        }
+        return lines;
+    }
+
+    // Simulate the monkeys with the full worry levels, without reducing them. A product of two
+    // levels that fit in a `u64` always fits in a `u128`, so this can tell when they stop fitting.
+    fn naive_business(
+        monkeys: &[Monkey],
+        rounds: usize,
+        worry_reduction: u128,
+    ) -> Result<u64, Overflow> {
+        let mut items: Vec<Vec<u128>> = monkeys
+            .iter()
+            .map(|monkey| monkey.items.iter().map(|&item| item as u128).collect())
+            .collect();
+        let mut counts = vec![0; monkeys.len()];
+        for round in 1..=rounds {
+            for (i, monkey) in monkeys.iter().enumerate() {
+                for item in std::mem::take(&mut items[i]) {
+                    counts[i] += 1;
+                    let item = match monkey.operation {
+                        Operation::Add(n) => item + n as u128,
+                        Operation::Multiply(n) => item * n as u128,
+                        Operation::Square => item * item,
+                    };
+                    // Without the division, monkey_business keeps its levels small instead.
+                    if worry_reduction > 1 && item > u64::MAX as u128 {
+                        return Err(Overflow { round, monkey: i });
+                    }
+                    let item = item / worry_reduction;
+                    let throw_at = if item.is_multiple_of(monkey.mod_value as u128) {
+                        monkey.on_true
+                    } else {
+                        monkey.on_false
+                    };
+                    items[throw_at].push(item);
+                }
+            }
+        }
+        counts.sort_by(|a, b| b.cmp(a));
+        return Ok(counts[0] * counts[1]);
+    }
+
+    #[test]
+    fn pt1_test() {
+        let monkeys = parse_lines(&read_file("test_input")).unwrap();
+        let result = part1(monkeys);
+        assert_eq!(result, Ok(10605))
+    }
+
+    #[test]
+    fn pt2_test() {
+        let monkeys = parse_lines(&read_file("test_input")).unwrap();
+        let result = part2(monkeys);
+        assert_eq!(result, Ok(2713310158));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let mut lines = read_file("test_input");
+        lines[4] = String::from("If true: throw to monkey 0");
+        let err = parse_lines(&lines).unwrap_err();
+        assert_eq!((err.line, err.column), (5, 26));
+        assert_eq!(err.expected, "another monkey's number");
+
+        let mut lines = read_file("test_input");
+        lines.truncate(21);
+        let err = parse_lines(&lines).unwrap_err();
+        assert_eq!(err.line, 21);
+        assert_eq!(err.expected, "`Test: divisible by N`");
+    }
+
+    #[test]
+    fn worry_reduction_test() {
+        let monkey = |item: Option<u64>, operation, mod_value, target| Monkey {
+            items: item.into_iter().collect(),
+            operation,
+            mod_value,
+            on_true: target,
+            on_false: target,
+        };
+        let mut monkeys = vec![
+            monkey(Some(20), Operation::Add(1), 2, 1),
+            monkey(None, Operation::Add(0), 3, 0),
+        ];
+
+        // 20 + 1 = 21 becomes 7, which is 1 modulo 6, but monkey 1 has to divide 7 rather than 1.
+        monkey_business(&mut monkeys, 1, 3).unwrap();
+        assert_eq!(monkeys[0].items, [2]);
+    }
+
+    #[test]
+    fn overflow_test() {
+        let monkey = |item, target| Monkey {
+            items: VecDeque::from([item]),
+            operation: Operation::Square,
+            mod_value: 7,
+            on_true: target,
+            on_false: target,
+        };
+
+        // 1000 squares to 333333 after dividing, then 37036962963, and the next square doesn't fit.
+        let monkeys = vec![monkey(1000, 1), monkey(3, 0)];
+        let result = part1(monkeys);
+        assert_eq!(
+            result,
+            Err(Overflow {
+                round: 2,
+                monkey: 0
+            })
+        );
+        let err = Day11::part1(&vec![monkey(1000, 1), monkey(3, 0)]).unwrap_err();
+        assert_eq!(err.message, "monkey 0's worry level overflowed in round 2");
+
+        // Part 2 keeps its levels below 7, so squaring them never overflows.
+        let monkeys = vec![monkey(1000, 1), monkey(3, 0)];
+        assert!(part2(monkeys).is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_roundtrip(monkeys in monkeys(true)) {
+            prop_assert_eq!(parse_lines(&render(&monkeys)).unwrap(), monkeys);
+        }
+
+        // Squaring is left out, as it overflows the unreduced worry levels within a few rounds.
+        #[test]
+        fn business_matches_naive(monkeys in monkeys(false), rounds in 1..4usize, worry_reduction in 1..=3u64) {
+            let expected = naive_business(&monkeys, rounds, worry_reduction as u128);
+            prop_assert_eq!(monkey_business(&mut monkeys.clone(), rounds, worry_reduction), expected);
+        }
+
+        // With the worry division there is no reduction, so squaring either gives the same
+        // business as the full levels or overflows at the same point.
+        #[test]
+        fn squaring_matches_naive(monkeys in monkeys(true), rounds in 1..4usize, worry_reduction in 2..=3u64) {
+            let expected = naive_business(&monkeys, rounds, worry_reduction as u128);
+            prop_assert_eq!(monkey_business(&mut monkeys.clone(), rounds, worry_reduction), expected);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+use day12::Day12;
+
+aoc_common::bench_main!(Day12, ["input"], sample_size = 10);
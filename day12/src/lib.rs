@@ -0,0 +1,256 @@
+use aoc_common::{parse_non_empty_lines, Grid, ParseError, Solution, SolveError};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+type Point = aoc_common::Point<usize>;
+
+// The height map, start point, goal point and every possible start point for part 2.
+pub type ParsedMap = (Grid<char>, Point, Point, HashSet<Point>);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct OpenPoint {
+    score: u32,
+    loc: (usize, usize),
+}
+
+impl Ord for OpenPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .cmp(&self.score)
+            .then_with(|| other.loc.cmp(&self.loc))
+    }
+}
+
+impl PartialOrd for OpenPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn parse_lines(lines: &Vec<String>) -> Result<ParsedMap, ParseError> {
+    let mut map = Grid::parse(lines, "a height from `a` to `z`, `S` or `E`", |c| {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+    })?;
+
+    let mut start = None;
+    let mut goal = None;
+    let mut all_starts = HashSet::new();
+    for pos in map.positions() {
+        match map[pos] {
+            'S' => {
+                start = Some(pos);
+                all_starts.insert(pos);
+                map[pos] = 'a';
+            }
+            'E' => {
+                goal = Some(pos);
+                map[pos] = 'z';
+            }
+            'a' => {
+                all_starts.insert(pos);
+            }
+            _ => (),
+        }
+    }
+
+    // A missing start or goal is reported at the end of the map.
+    let missing = |expected: &str| {
+        let last = lines.last().map_or("", |line| line.as_str());
+        ParseError::in_line(lines.len().max(1), last, last.len(), expected)
+    };
+    let start = start.ok_or_else(|| missing("a start point `S`"))?;
+    let goal = goal.ok_or_else(|| missing("a goal point `E`"))?;
+
+    return Ok((map, start, goal, all_starts));
+}
+
+fn heuristic(a: Point, goal: Point) -> u32 {
+    return ((a.0 as i32 - goal.0 as i32).abs() + (a.1 as i32 - goal.1 as i32).abs()) as u32;
+}
+
+fn find_shortest_path(map: &Grid<char>, start: Point, goal: Point) -> Option<usize> {
+    // Using A* to find the path from start to goal.
+    let mut came_from: HashMap<Point, Point> = HashMap::from([(start, start)]);
+    let mut scores: HashMap<Point, u32> = HashMap::from([(start, 0)]);
+
+    let mut queue = BinaryHeap::new();
+    queue.push(OpenPoint {
+        score: 0,
+        loc: start,
+    });
+
+    while let Some(OpenPoint {
+        score: _,
+        loc: current_loc,
+    }) = queue.pop()
+    {
+        if current_loc == goal {
+            // Reached the goal, rebuild the path to find number of steps
+            let mut distance = 0;
+            let mut current = current_loc;
+            while current != start {
+                current = came_from[&current];
+                distance += 1;
+            }
+
+            return Some(distance);
+        }
+
+        let neighbours: Vec<Point> = map
+            .neighbours4(current_loc)
+            .filter(|&loc|
+                // Filter climbs that are too steep
+                (map[loc] as u32) <= (map[current_loc] as u32) + 1)
+            .collect();
+
+        for n in neighbours {
+            let new_score = scores[&current_loc] + 1;
+
+            if !scores.contains_key(&n) || new_score < scores[&n] {
+                scores.insert(n, new_score);
+                queue.push(OpenPoint {
+                    score: new_score + heuristic(n, goal),
+                    loc: n,
+                });
+                came_from.insert(n, current_loc);
+            }
+        }
+    }
+
+    // Failed to reach the goal.
+    return None;
+}
+
+pub fn part1(map: &Grid<char>, start: (usize, usize), goal: (usize, usize)) -> usize {
+    return find_shortest_path(map, start, goal).expect("Didn't find path to goal");
+}
+
+pub fn part2(map: &Grid<char>, starts: &HashSet<Point>, goal: (usize, usize)) -> usize {
+    // Just run pathfinding on each possible start, throwing aways the path details each time.
+    // This isn't optimal - we could reuse any the path details from previous runs - but it still
+    // executes quickly enough for the given input.
+    starts
+        .iter()
+        .filter_map(|&start| find_shortest_path(map, start, goal))
+        .min()
+        .expect("Couldn't find minimum path length")
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = ParsedMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        let (map, start, goal, _) = input;
+        return Ok(part1(map, *start, *goal));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        let (map, _, goal, starts) = input;
+        return Ok(part2(map, starts, *goal));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
+
+    // A small height map, and a start and goal somewhere on it.
+    fn map_and_route() -> impl Strategy<Value = (Grid<char>, Point, Point)> {
+        return (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+            let rows = prop::collection::vec(
+                prop::collection::vec(prop::char::range('a', 'd'), width),
+                height,
+            );
+            let pos = (0..width, 0..height);
+            return (
+                rows.prop_map(|rows| Grid::from_rows(rows).unwrap()),
+                pos.clone(),
+                pos,
+            );
+        });
+    }
+
+    // Plain breadth first search, for comparison with the A* search.
+    fn bfs(map: &Grid<char>, start: Point, goal: Point) -> Option<usize> {
+        let mut distances = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            if current == goal {
+                return Some(distances[&current]);
+            }
+            for next in map.neighbours4(current) {
+                if (map[next] as u32) <= (map[current] as u32) + 1 && !distances.contains_key(&next)
+                {
+                    distances.insert(next, distances[&current] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        return None;
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = vec![
+            String::from("Sabqponm"),
+            String::from("abcryxxl"),
+            String::from("accszExk"),
+            String::from("acctuvwj"),
+            String::from("abdefghi"),
+        ];
+
+        let (map, start, end, _) = parse_lines(&lines).unwrap();
+        let result = part1(&map, start, end);
+        assert_eq!(result, 31);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = vec![
+            String::from("Sabqponm"),
+            String::from("abcryxxl"),
+            String::from("accszExk"),
+            String::from("acctuvwj"),
+            String::from("abdefghi"),
+        ];
+
+        let (map, _, end, starts) = parse_lines(&lines).unwrap();
+        let result = part2(&map, &starts, end);
+        assert_eq!(result, 29);
+    }
+
+    #[test]
+    fn short_path_test() {
+        let lines = vec![String::from("SbE")];
+        let (map, start, _, _) = parse_lines(&lines).unwrap();
+        let next = map.neighbours4(start).find(|&pos| map[pos] == 'b').unwrap();
+        assert_eq!(find_shortest_path(&map, start, start), Some(0));
+        assert_eq!(find_shortest_path(&map, start, next), Some(1));
+    }
+
+    proptest! {
+        #[test]
+        fn shortest_path_matches_bfs((map, start, goal) in map_and_route()) {
+            prop_assert_eq!(find_shortest_path(&map, start, goal), bfs(&map, start, goal));
+        }
+
+        #[test]
+        fn flat_map_distance_is_manhattan((map, start, goal) in map_and_route()) {
+            let flat = Grid::new(map.width(), map.height(), 'a');
+            prop_assert_eq!(find_shortest_path(&flat, start, goal), Some(heuristic(start, goal) as usize));
+        }
+    }
+}
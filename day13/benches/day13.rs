@@ -0,0 +1,3 @@
+use day13::Day13;
+
+aoc_common::bench_main!(Day13, ["input", "test_input"]);
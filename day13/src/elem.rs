@@ -35,30 +35,30 @@ fn cmp_elem_list(self_list: &Vec<Elem>, other_list: &Vec<Elem>) -> Ordering {
 
 impl Ord for Elem {
     fn cmp(&self, other: &Self) -> Ordering {
-        if self.number.is_some() && other.number.is_some() {
-            return self.number.unwrap().cmp(&other.number.unwrap());
+        if let (Some(self_num), Some(other_num)) = (self.number, other.number) {
+            return self_num.cmp(&other_num);
         }
 
-        if self.list.is_some() && other.list.is_some() {
-            return cmp_elem_list(&self.list.as_ref().unwrap(), other.list.as_ref().unwrap());
+        if let (Some(self_list), Some(other_list)) = (&self.list, &other.list) {
+            return cmp_elem_list(self_list, other_list);
         }
 
-        if self.list.is_some() && other.number.is_some() {
+        if let (Some(self_list), Some(other_num)) = (&self.list, other.number) {
             let tmp = vec![Elem {
                 list: None,
-                number: Some(other.number.unwrap()),
+                number: Some(other_num),
                 divider: false,
             }];
-            return cmp_elem_list(&self.list.as_ref().unwrap(), &tmp);
+            return cmp_elem_list(self_list, &tmp);
         }
 
-        if self.number.is_some() && other.list.is_some() {
+        if let (Some(self_num), Some(other_list)) = (self.number, &other.list) {
             let tmp = vec![Elem {
                 list: None,
-                number: Some(self.number.unwrap()),
+                number: Some(self_num),
                 divider: false,
             }];
-            return cmp_elem_list(&tmp, &other.list.as_ref().unwrap());
+            return cmp_elem_list(&tmp, other_list);
         }
 
         panic!("Invalid elem");
@@ -71,8 +71,9 @@ impl PartialOrd for Elem {
     }
 }
 
+// Equality has to agree with the ordering, where `[1]` and `[[1]]` are equal.
 impl PartialEq for Elem {
     fn eq(&self, other: &Self) -> bool {
-        self.list == other.list && self.number == other.number
+        self.cmp(other) == Ordering::Equal
     }
 }
@@ -0,0 +1,213 @@
+pub mod elem;
+
+use crate::elem::Elem;
+use aoc_common::{parse_non_empty_lines, ParseError, Solution, SolveError};
+use std::cmp::Ordering;
+
+fn parse_line(line_number: usize, line: &str, divider: bool) -> Result<Elem, ParseError> {
+    let error =
+        |offset: usize, expected: &str| ParseError::in_line(line_number, line, offset, expected);
+
+    // The root element is always a list.
+    if !line.starts_with('[') {
+        return Err(error(0, "`[`"));
+    }
+
+    let mut list_stack: Vec<Elem> = Vec::new();
+    let mut num_start = None;
+
+    for (offset, c) in line.char_indices() {
+        if c.is_ascii_digit() {
+            num_start.get_or_insert(offset);
+            continue;
+        }
+
+        // Add complete numbers to the currently open list.
+        if let Some(start) = num_start.take() {
+            let num = line[start..offset]
+                .parse::<u32>()
+                .map_err(|_| error(start, "a number"))?;
+            let current_elem = list_stack.last_mut().expect("A list is always open");
+            current_elem.list.as_mut().unwrap().push(Elem {
+                list: None,
+                number: Some(num),
+                divider,
+            });
+        }
+
+        match c {
+            '[' => list_stack.push(Elem {
+                list: Some(Vec::new()),
+                number: None,
+                divider,
+            }),
+            ']' => {
+                let popped = list_stack.pop().expect("A list is always open");
+
+                if list_stack.is_empty() {
+                    if offset + 1 < line.len() {
+                        return Err(error(offset + 1, "end of line"));
+                    }
+                    return Ok(popped);
+                } else {
+                    let parent = list_stack.last_mut().unwrap();
+                    parent.list.as_mut().unwrap().push(popped);
+                }
+            }
+            ',' => (),
+            _ => return Err(error(offset, "`[`, `]`, `,` or a number")),
+        }
+    }
+
+    return Err(error(line.len(), "`]`"));
+}
+
+fn parse_divider(line: &str) -> Elem {
+    parse_line(1, line, true).expect("Failed to parse divider packet")
+}
+
+pub fn parse_lines(lines: &Vec<String>) -> Result<Vec<Elem>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| parse_line(index + 1, line, false))
+        .collect()
+}
+
+pub fn part1(elems: &Vec<Elem>) -> usize {
+    return elems
+        .chunks(2)
+        .map(|pair| pair[0].cmp(&pair[1]))
+        .enumerate()
+        .map(|(i, c)| if c == Ordering::Less { i + 1 } else { 0 })
+        .sum();
+}
+
+pub fn part2(elems: &Vec<Elem>) -> usize {
+    let mut sorted = elems.clone();
+    sorted.push(parse_divider("[[2]]"));
+    sorted.push(parse_divider("[[6]]"));
+    sorted.sort();
+
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(i, elem)| if elem.divider { i + 1 } else { 1 })
+        .product()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Elem>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::read_file;
+    use proptest::prelude::*;
+
+    // The text of a packet, which is always a list at the root.
+    fn packet() -> impl Strategy<Value = String> {
+        let leaf = (0..11u32).prop_map(|n| n.to_string());
+        let value = leaf.prop_recursive(4, 24, 4, |inner| {
+            prop::collection::vec(inner, 0..4).prop_map(|items| format!("[{}]", items.join(",")))
+        });
+        return prop::collection::vec(value, 0..4)
+            .prop_map(|items| format!("[{}]", items.join(",")));
+    }
+
+    fn parse_packet(text: &str) -> Elem {
+        return parse_line(1, text, false).unwrap();
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = read_file("test_input");
+        let elems = parse_lines(&lines).unwrap();
+
+        let result = part1(&elems);
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = read_file("test_input");
+        let elems = parse_lines(&lines).unwrap();
+
+        let result = part2(&elems);
+        assert_eq!(result, 140);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let lines = vec![String::from("[1,[2,x]]")];
+        let err = parse_lines(&lines).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        assert_eq!(err.found, "x");
+
+        let lines = vec![String::from("[1,[2]")];
+        let err = parse_lines(&lines).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (7, "`]`"));
+    }
+
+    #[test]
+    fn equality_test() {
+        let a = parse_line(1, "[1]", false).unwrap();
+        let b = parse_line(1, "[[1]]", false).unwrap();
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(a, b);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_roundtrip(text in packet()) {
+            let printed = format!("{:?}", parse_packet(&text)).replace(' ', "");
+            prop_assert_eq!(printed, text);
+        }
+
+        #[test]
+        fn ordering_is_consistent(a in packet(), b in packet(), c in packet()) {
+            let (a, b, c) = (parse_packet(&a), parse_packet(&b), parse_packet(&c));
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a == b, a.cmp(&b) == Ordering::Equal);
+            prop_assert_eq!(a.cmp(&a), Ordering::Equal);
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+        }
+
+        #[test]
+        fn wrapping_in_a_list_keeps_order(a in packet(), b in packet()) {
+            let wrapped = |text: &str| parse_packet(&format!("[{}]", text));
+            let (pa, pb) = (parse_packet(&a), parse_packet(&b));
+            prop_assert_eq!(wrapped(&a).cmp(&wrapped(&b)), pa.cmp(&pb));
+        }
+
+        #[test]
+        fn dividers_placed_by_count(texts in prop::collection::vec(packet(), 0..20)) {
+            let packets = parse_lines(&texts).unwrap();
+
+            // The sort is stable and the dividers go in last, so they come after equal packets.
+            let (two, six) = (parse_divider("[[2]]"), parse_divider("[[6]]"));
+            let first = 1 + packets.iter().filter(|p| **p <= two).count();
+            let second = 2 + packets.iter().filter(|p| **p <= six).count();
+            prop_assert_eq!(part2(&packets), first * second);
+        }
+    }
+}
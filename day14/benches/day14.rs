@@ -0,0 +1,3 @@
+use day14::Day14;
+
+aoc_common::bench_main!(Day14, ["input"]);
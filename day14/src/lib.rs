@@ -0,0 +1,287 @@
+use aoc_common::{parse_non_empty_lines, Grid, ParseError, Solution, SolveError};
+use std::fmt;
+
+type Point = aoc_common::Point<usize>;
+
+// Where the sand pours in from.
+const SOURCE: Point = (500, 0);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+// The cave, down to one row below the lowest rock, which is where the floor sits on top of.
+pub type Map = Grid<Tile>;
+
+fn parse_point(line_number: usize, line: &str, offset: usize) -> Result<Point, ParseError> {
+    let error =
+        |offset: usize, expected: &str| ParseError::in_line(line_number, line, offset, expected);
+    let chunk = &line[offset
+        ..line[offset..]
+            .find(' ')
+            .map_or(line.len(), |end| offset + end)];
+
+    let (x, y) = chunk
+        .split_once(',')
+        .ok_or_else(|| error(offset, "a point like `498,4`"))?;
+    let x = x
+        .parse::<usize>()
+        .map_err(|_| error(offset, "a coordinate"))?;
+    let y = y
+        .parse::<usize>()
+        .map_err(|_| error(offset + chunk.len() - y.len(), "a coordinate"))?;
+
+    return Ok((x, y));
+}
+
+pub fn parse_lines(lines: &Vec<String>) -> Result<Map, ParseError> {
+    let mut rocks = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let mut prev_point: Option<Point> = None;
+        let mut offset = 0;
+
+        for chunk in line.split(" -> ") {
+            let new = parse_point(index + 1, line, offset)?;
+            // A path can be a single point.
+            rocks.push(new);
+
+            if let Some(prev) = prev_point {
+                // Walls are only ever horizontal or vertical.
+                if new.0 != prev.0 && new.1 != prev.1 {
+                    return Err(ParseError::in_line(
+                        index + 1,
+                        line,
+                        offset,
+                        "a point in line with the previous one",
+                    ));
+                }
+
+                for x in prev.0.min(new.0)..=prev.0.max(new.0) {
+                    for y in prev.1.min(new.1)..=prev.1.max(new.1) {
+                        rocks.push((x, y));
+                    }
+                }
+            }
+
+            prev_point = Some(new);
+            offset += chunk.len() + " -> ".len();
+        }
+    }
+
+    // Sand can't spread further sideways from the source than it falls, so the map only needs to
+    // be as wide as it is deep either side of the source.
+    let height = rocks.iter().map(|rock| rock.1).max().unwrap_or(0) + 2;
+    let width = rocks
+        .iter()
+        .map(|rock| rock.0 + 1)
+        .chain([SOURCE.0 + height + 1])
+        .max()
+        .unwrap();
+
+    let mut map = Grid::new(width, height, Tile::Air);
+    for rock in rocks {
+        map[rock] = Tile::Rock;
+    }
+
+    Ok(map)
+}
+
+// Drop a grain of sand from the source, and find where it comes to rest. Without a floor the
+// grain can fall out of the bottom of the map, and never comes to rest.
+fn drop_grain(map: &Map, floor: bool) -> Option<Point> {
+    let mut pos = SOURCE;
+
+    'falling: loop {
+        // The floor is just below the bottom row of the map.
+        if floor && pos.1 + 1 == map.height() {
+            return Some(pos);
+        }
+
+        for dir in [(0, 1), (-1, 1), (1, 1)] {
+            match map.step(pos, dir) {
+                // We found a place this grain can go, move it down.
+                Some(candidate) if map[candidate] == Tile::Air => {
+                    pos = candidate;
+                    continue 'falling;
+                }
+                Some(_) => (),
+                None => return None,
+            }
+        }
+
+        return Some(pos);
+    }
+}
+
+// Pour sand in until it either falls out of the bottom of the map, or blocks the source.
+fn run_sim(mut map: Map, floor: bool) -> u32 {
+    let mut grain_count = 0;
+
+    while let Some(pos) = drop_grain(&map, floor) {
+        grain_count += 1;
+        map[pos] = Tile::Sand;
+
+        if pos == SOURCE {
+            break;
+        }
+    }
+
+    grain_count
+}
+
+pub fn part1(map: &Map) -> u32 {
+    run_sim(map.clone(), false)
+}
+
+pub fn part2(map: &Map) -> u32 {
+    run_sim(map.clone(), true)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Map;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    // Paths of rock near the source, each a start point and a list of straight segments.
+    fn rock_paths() -> impl Strategy<Value = Vec<(Point, Vec<(bool, i32)>)>> {
+        let segment = (any::<bool>(), -5..=5i32);
+        let path = (
+            (490..510usize, 21..32usize),
+            prop::collection::vec(segment, 0..4),
+        );
+        return prop::collection::vec(path, 1..6);
+    }
+
+    // The text of each path, along with every rock in them.
+    fn render(paths: &[(Point, Vec<(bool, i32)>)]) -> (Vec<String>, HashSet<Point>) {
+        let mut lines = Vec::new();
+        let mut rocks = HashSet::new();
+        for (start, segments) in paths {
+            let mut pos = *start;
+            let mut points = vec![format!("{},{}", pos.0, pos.1)];
+            rocks.insert(pos);
+            for &(vertical, len) in segments {
+                let step = len.signum() as isize;
+                for _ in 0..len.abs() {
+                    pos = if vertical {
+                        (pos.0, pos.1.wrapping_add_signed(step))
+                    } else {
+                        (pos.0.wrapping_add_signed(step), pos.1)
+                    };
+                    rocks.insert(pos);
+                }
+                points.push(format!("{},{}", pos.0, pos.1));
+            }
+            lines.push(points.join(" -> "));
+        }
+        return (lines, rocks);
+    }
+
+    // With a floor, a cell fills with sand if it isn't rock and one of the three cells above it
+    // has sand, so the sand can be counted a row at a time.
+    fn floor_sand(map: &Map) -> u32 {
+        let mut row = HashSet::from([SOURCE.0]);
+        let mut count = 1;
+        for y in 1..map.height() {
+            row = row
+                .iter()
+                .flat_map(|&x| [x - 1, x, x + 1])
+                .filter(|&x| map[(x, y)] != Tile::Rock)
+                .collect();
+            count += row.len() as u32;
+        }
+        return count;
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = vec![
+            String::from("498,4 -> 498,6 -> 496,6"),
+            String::from("503,4 -> 502,4 -> 502,9 -> 494,9"),
+        ];
+
+        let map = parse_lines(&lines).unwrap();
+        let result = part1(&map);
+        assert_eq!(result, 24);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = vec![
+            String::from("498,4 -> 498,6 -> 496,6"),
+            String::from("503,4 -> 502,4 -> 502,9 -> 494,9"),
+        ];
+
+        let map = parse_lines(&lines).unwrap();
+        let result = part2(&map);
+        assert_eq!(result, 93);
+    }
+
+    #[test]
+    fn single_point_test() {
+        // Three single rocks side by side hold the same sand as a path through all of them.
+        let points = ["499,2", "500,2", "501,2"].map(String::from).to_vec();
+        let path = vec![String::from("499,2 -> 501,2")];
+        let (points, path) = (parse_lines(&points).unwrap(), parse_lines(&path).unwrap());
+        assert_eq!(points, path);
+        assert!(part1(&points) > 0);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_places_rocks(paths in rock_paths()) {
+            let (lines, rocks) = render(&paths);
+            let map = parse_lines(&lines).unwrap();
+            let parsed: HashSet<Point> = map
+                .iter()
+                .filter(|(_, &tile)| tile == Tile::Rock)
+                .map(|(pos, _)| pos)
+                .collect();
+            prop_assert_eq!(parsed, rocks);
+        }
+
+        #[test]
+        fn sand_fills_triangle_with_floor(paths in rock_paths()) {
+            let map = parse_lines(&render(&paths).0).unwrap();
+            let with_floor = part2(&map);
+            prop_assert_eq!(with_floor, floor_sand(&map));
+            prop_assert!(part1(&map) <= with_floor);
+        }
+    }
+}
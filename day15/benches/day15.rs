@@ -0,0 +1,3 @@
+use day15::Day15;
+
+aoc_common::bench_main!(Day15, ["input"], sample_size = 10);
@@ -0,0 +1,344 @@
+use aoc_common::{parse_non_empty_lines, parse_num, ParseError, Point, Solution, SolveError};
+use regex::Regex;
+use std::cmp::{max, Ordering};
+use std::ops::RangeInclusive;
+
+#[derive(Debug)]
+pub struct Sensor {
+    loc: Point,
+    beacon: Point,
+}
+
+pub fn parse_lines(lines: &[String]) -> Result<Vec<Sensor>, ParseError> {
+    let re = Regex::new(r"^Sensor at x=(?P<sx>-?\d+), y=(?P<sy>-?\d+): closest beacon is at x=(?P<bx>-?\d+), y=(?P<by>-?\d+)$").expect("Failed to build regex");
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, l)| {
+            let line = index + 1;
+            let caps = re.captures(l).ok_or_else(|| {
+                ParseError::bad_line(
+                    line,
+                    l,
+                    "`Sensor at x=X, y=Y: closest beacon is at x=X, y=Y`",
+                )
+            })?;
+            Ok(Sensor {
+                loc: (parse_num(&caps, "sx", line)?, parse_num(&caps, "sy", line)?),
+                beacon: (parse_num(&caps, "bx", line)?, parse_num(&caps, "by", line)?),
+            })
+        })
+        .collect()
+}
+
+fn coverage_for_sensor(
+    sensor: &Sensor,
+    target_row: i32,
+    skip_beacon: bool,
+) -> Option<RangeInclusive<i32>> {
+    let beacon_distance =
+        (sensor.beacon.0 - sensor.loc.0).abs() + (sensor.beacon.1 - sensor.loc.1).abs();
+    let row_distance = (sensor.loc.1 - target_row).abs();
+
+    // Row width is the width of the target row that is covered by this sensor. If it's negative
+    // that means the sensor is closer to the beacon than the target row, so doesn't cover any of
+    // that row. Note that this is actually half the actual row width, less the centre.
+    let row_width = beacon_distance - row_distance;
+    if row_width < 0 {
+        return None;
+    }
+
+    let mut start = sensor.loc.0 - row_width;
+    let mut end = sensor.loc.0 + row_width;
+
+    // If the beacon is in this row, exclude it.
+    if skip_beacon && sensor.beacon.1 == target_row {
+        if sensor.beacon.0 == start {
+            start += 1;
+        } else {
+            end -= 1;
+        }
+    }
+
+    // Excluding the beacon can leave nothing covered.
+    (start <= end).then_some(start..=end)
+}
+
+// Count the number of definitely-empty spaces in a line
+fn count_empty(sensors: &[Sensor], target_row: i32) -> i32 {
+    let mut ranges = vec![];
+
+    for sensor in sensors {
+        if let Some(range) = coverage_for_sensor(sensor, target_row, true) {
+            ranges.push(range);
+        }
+    }
+
+    // Sort the ranges so that we can find if there any overlaps.
+    ranges.sort_by(|a, b| {
+        let cmp = a.start().cmp(b.start());
+
+        if cmp != Ordering::Equal {
+            cmp
+        } else {
+            a.end().cmp(b.end())
+        }
+    });
+
+    // The furthest column covered by the ranges so far.
+    let mut empty_count = 0;
+    let mut prev_end: Option<i32> = None;
+    for cur in ranges {
+        if let Some(prev) = prev_end {
+            if *cur.end() <= prev {
+                // Completely overlapped by the previous ranges, skip it.
+                continue;
+            } else if *cur.start() <= prev {
+                // Partial overlap with previous ranges - add just the non-overlapping bits.
+                empty_count += cur.end() - prev;
+            } else {
+                // No overlap with previous ranges - add the full range.
+                empty_count += cur.end() - cur.start() + 1;
+            }
+        } else {
+            empty_count += cur.end() - cur.start() + 1;
+        }
+
+        prev_end = Some(*cur.end());
+    }
+
+    empty_count
+}
+
+// For part 2, find if a given row has a gap where in coverage where a beacon could be.
+fn find_hidden_beacon_in_row(
+    sensors: &[Sensor],
+    target_row: i32,
+    cols: &RangeInclusive<i32>,
+) -> Option<i32> {
+    let mut ranges = vec![];
+
+    for sensor in sensors {
+        if let Some(range) = coverage_for_sensor(sensor, target_row, false) {
+            if range.end() >= cols.start() && range.start() <= cols.end() {
+                ranges.push(range);
+            }
+        }
+    }
+
+    ranges.sort_by(|a, b| {
+        let cmp = a.start().cmp(b.start());
+
+        if cmp != Ordering::Equal {
+            cmp
+        } else {
+            a.end().cmp(b.end())
+        }
+    });
+
+    // Throughout this we assume that there will only be a single location that
+    // a hidden beacon could be.
+    let mut maybe_max: Option<i32> = None;
+    for range in ranges {
+        if let Some(cur_max) = maybe_max {
+            if *range.start() > cur_max + 1 {
+                // There is a gap between the ranges, the hidden beacon must be in the cap.
+                return Some(cur_max + 1);
+            }
+            maybe_max = Some(max(cur_max, *range.end()));
+        } else {
+            if range.start() > cols.start() {
+                // If the first range doesn't cover the beginning of the row, the
+                // hidden beacon must be at the start of the row.
+                return Some(*cols.start());
+            }
+            maybe_max = Some(*range.end());
+        }
+    }
+
+    match maybe_max {
+        // We didn't reach the end of the row, the hidden beacon must
+        // be just after the last range.
+        Some(cur_max) if cur_max < *cols.end() => Some(cur_max + 1),
+        Some(_) => None,
+        // No sensor reaches this row at all.
+        None => Some(*cols.start()),
+    }
+}
+
+fn find_hidden_beacon(
+    sensors: &[Sensor],
+    rows: RangeInclusive<i32>,
+    cols: RangeInclusive<i32>,
+) -> Option<Point> {
+    for row in rows {
+        if let Some(x) = find_hidden_beacon_in_row(sensors, row, &cols) {
+            return Some((x, row));
+        }
+    }
+
+    None
+}
+
+fn find_tuning_freq(
+    sensors: &[Sensor],
+    rows: RangeInclusive<i32>,
+    cols: RangeInclusive<i32>,
+) -> Option<i64> {
+    find_hidden_beacon(sensors, rows, cols).map(|loc| loc.0 as i64 * 4000000 + loc.1 as i64)
+}
+
+pub fn part1(sensors: &[Sensor]) -> i32 {
+    count_empty(sensors, 2000000)
+}
+
+pub fn part2(sensors: &[Sensor]) -> i64 {
+    find_tuning_freq(sensors, 0..=4000000, 0..=4000000).expect("Failed to find hidden beacon")
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_non_empty_lines(input, |lines| parse_lines(lines))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::read_file;
+    use proptest::prelude::*;
+
+    fn distance(a: Point, b: Point) -> i32 {
+        (a.0 - b.0).abs() + (a.1 - b.1).abs()
+    }
+
+    // Sensors near the origin, where no beacon is closer to a sensor than the one it reports, and
+    // there are no ties, as the puzzle promises.
+    fn sensors() -> impl Strategy<Value = Vec<Sensor>> {
+        let point = || (-10..=10i32, -10..=10i32);
+        prop::collection::vec((point(), point()), 1..5)
+            .prop_map(|pairs| {
+                pairs
+                    .into_iter()
+                    .map(|(loc, beacon)| Sensor { loc, beacon })
+                    .collect::<Vec<_>>()
+            })
+            .prop_filter("another beacon is as close to a sensor", |sensors| {
+                sensors.iter().all(|sensor| {
+                    let range = distance(sensor.loc, sensor.beacon);
+                    sensors.iter().all(|other| {
+                        other.beacon == sensor.beacon || distance(sensor.loc, other.beacon) > range
+                    })
+                })
+            })
+    }
+
+    fn covered(sensors: &[Sensor], pos: Point) -> bool {
+        sensors
+            .iter()
+            .any(|sensor| distance(sensor.loc, pos) <= distance(sensor.loc, sensor.beacon))
+    }
+
+    fn render(sensors: &[Sensor]) -> Vec<String> {
+        sensors
+            .iter()
+            .map(|sensor| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    sensor.loc.0, sensor.loc.1, sensor.beacon.0, sensor.beacon.1
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = read_file("test_input");
+        let sensors = parse_lines(&lines).unwrap();
+        let result = count_empty(&sensors, 10);
+        assert_eq!(result, 26);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = read_file("test_input");
+        let sensors = parse_lines(&lines).unwrap();
+        let result = find_tuning_freq(&sensors, 0..=20, 0..=20);
+        assert_eq!(result, Some(56000011));
+    }
+
+    fn sensor(loc: Point, beacon: Point) -> Sensor {
+        Sensor { loc, beacon }
+    }
+
+    #[test]
+    fn empty_count_edge_test() {
+        // A row at exactly a sensor's range has one covered cell, unless the beacon is there.
+        let sensors = [sensor((0, 0), (2, 0))];
+        assert_eq!(count_empty(&sensors, 2), 1);
+        assert_eq!(count_empty(&sensors, 3), 0);
+        assert_eq!(count_empty(&[sensor((0, 0), (0, 2))], 2), 0);
+
+        // Ranges inside an earlier one are only counted once.
+        let sensors = [
+            sensor((0, 0), (0, 5)),
+            sensor((-3, 0), (-3, 1)),
+            sensor((3, 0), (3, 1)),
+        ];
+        assert_eq!(count_empty(&sensors, 0), 11);
+    }
+
+    #[test]
+    fn hidden_beacon_edge_test() {
+        // A gap after the last range starts just after it, not at the end of the row.
+        let sensors = [sensor((0, 0), (1, 0))];
+        assert_eq!(find_hidden_beacon(&sensors, 0..=0, 0..=3), Some((2, 0)));
+
+        // A row no sensor reaches is empty from its first column.
+        assert_eq!(find_hidden_beacon(&sensors, 2..=2, 0..=3), Some((0, 2)));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_roundtrip(sensors in sensors()) {
+            let parsed = parse_lines(&render(&sensors)).unwrap();
+            let points = |sensors: &[Sensor]| -> Vec<(Point, Point)> {
+                sensors.iter().map(|s| (s.loc, s.beacon)).collect()
+            };
+            prop_assert_eq!(points(&parsed), points(&sensors));
+        }
+
+        #[test]
+        fn empty_count_matches_brute_force(sensors in sensors(), row in -12..=12i32) {
+            // No sensor reaches further than 50 columns from the origin.
+            let expected = (-60..=60)
+                .filter(|&x| covered(&sensors, (x, row)))
+                .filter(|&x| sensors.iter().all(|sensor| sensor.beacon != (x, row)))
+                .count();
+            prop_assert_eq!(count_empty(&sensors, row), expected as i32);
+        }
+
+        #[test]
+        fn hidden_beacon_matches_brute_force(sensors in sensors()) {
+            let expected = (-5..=5)
+                .flat_map(|y| (-5..=5).map(move |x| (x, y)))
+                .find(|&pos| !covered(&sensors, pos));
+            prop_assert_eq!(find_hidden_beacon(&sensors, -5..=5, -5..=5), expected);
+        }
+    }
+}
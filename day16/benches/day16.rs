@@ -0,0 +1,3 @@
+use day16::Day16;
+
+aoc_common::bench_main!(Day16, ["input", "test_input"], sample_size = 10);
@@ -0,0 +1,415 @@
+use aoc_common::{parse_non_empty_lines, parse_num, ParseError, Solution, SolveError};
+use itertools::Itertools;
+use pathfinding::directed::dijkstra::dijkstra;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+pub struct Valve {
+    flow: u32,
+    neighbours: Vec<String>,
+
+    // Vector containing all reachable valves, the distance from this valve, and the flow rate of the valve
+    paths: HashMap<String, u32>,
+}
+
+// Find paths between each node.
+fn build_paths(valves: &mut HashMap<String, Valve>) {
+    let labels: Vec<String> = valves.keys().cloned().collect();
+
+    for src_label in &labels {
+        for dst_label in &labels {
+            if src_label == dst_label {
+                continue;
+            }
+
+            let path = dijkstra(
+                &src_label,
+                |&label| valves[label].neighbours.iter().map(|l| (l, 1)),
+                |&label| label == dst_label,
+            );
+
+            if let Some((_, cost)) = path {
+                let src_valve = valves.get_mut(src_label).expect("Failed to find valve");
+                src_valve.paths.insert(dst_label.clone(), cost);
+            }
+        }
+    }
+}
+
+fn max_pressure_worker(
+    valves: &HashMap<String, Valve>,
+    current_label: &str,
+    minutes_remaining: u32,
+    remaining_labels: HashSet<&str>,
+    memo: &mut HashMap<String, u32>,
+) -> u32 {
+    let current_node = &valves[current_label];
+    if remaining_labels.is_empty() {
+        return minutes_remaining * current_node.flow;
+    }
+
+    let mut memo_key = remaining_labels
+        .iter()
+        .sorted()
+        .copied()
+        .collect::<String>();
+    memo_key.push_str(minutes_remaining.to_string().as_str());
+    memo_key.push_str(current_label);
+
+    let best;
+    if memo.contains_key(&memo_key) {
+        best = memo[&memo_key];
+    } else {
+        best = remaining_labels
+            .iter()
+            .filter_map(|&label| {
+                let time_to_open = current_node.paths[label] + 1;
+                if time_to_open >= minutes_remaining {
+                    None
+                } else {
+                    let mut new_labels = remaining_labels.clone();
+                    new_labels.remove(label);
+                    Some(max_pressure_worker(
+                        valves,
+                        label,
+                        minutes_remaining - time_to_open,
+                        new_labels,
+                        memo,
+                    ))
+                }
+            })
+            .max()
+            .unwrap_or(0);
+        memo.insert(memo_key, best);
+    }
+
+    minutes_remaining * current_node.flow + best
+}
+
+// Memoized depth-first search to find the highest
+fn max_pressure(valves: &HashMap<String, Valve>) -> u32 {
+    let mut labels: HashSet<&str> = valves.keys().map(|s| s.as_str()).collect();
+    let mut memo = HashMap::new();
+
+    for (k, v) in valves {
+        if v.flow == 0 {
+            labels.remove(k.as_str());
+        }
+    }
+
+    max_pressure_worker(valves, "AA", 30, labels, &mut memo)
+}
+
+fn max_pressure_double_worker(
+    valves: &HashMap<String, Valve>,
+    current_label: &str,
+    minutes_remaining: u32,
+    remaining_labels: HashSet<&str>,
+    first_pass: bool,
+    memo: &mut HashMap<String, u32>,
+) -> u32 {
+    let current_node = &valves[current_label];
+    if remaining_labels.is_empty() {
+        return minutes_remaining * current_node.flow;
+    }
+
+    let mut memo_key = remaining_labels
+        .iter()
+        .sorted()
+        .copied()
+        .collect::<String>();
+    memo_key.push_str(minutes_remaining.to_string().as_str());
+    memo_key.push_str(current_label);
+    memo_key.push_str(if first_pass { "H" } else { "E" });
+
+    let best;
+    if memo.contains_key(&memo_key) {
+        best = memo[&memo_key];
+    } else {
+        // The human can stop at any point and leave the remaining valves to the elephant.
+        let elephant_best = if first_pass {
+            max_pressure_double_worker(valves, "AA", 26, remaining_labels.clone(), false, memo)
+        } else {
+            0
+        };
+
+        best = remaining_labels
+            .iter()
+            .filter_map(|&label| {
+                let time_to_open = current_node.paths[label] + 1;
+                if time_to_open >= minutes_remaining {
+                    return None;
+                }
+
+                let mut new_labels = remaining_labels.clone();
+                new_labels.remove(label);
+                Some(max_pressure_double_worker(
+                    valves,
+                    label,
+                    minutes_remaining - time_to_open,
+                    new_labels,
+                    first_pass,
+                    memo,
+                ))
+            })
+            .max()
+            .unwrap_or(0)
+            .max(elephant_best);
+        memo.insert(memo_key, best);
+    }
+
+    minutes_remaining * current_node.flow + best
+}
+
+fn max_pressure_double(valves: &HashMap<String, Valve>) -> u32 {
+    let mut labels: HashSet<&str> = valves.keys().map(|s| s.as_str()).collect();
+    let mut memo: HashMap<String, u32> = HashMap::new();
+
+    for (k, v) in valves {
+        if v.flow == 0 {
+            labels.remove(k.as_str());
+        }
+    }
+
+    max_pressure_double_worker(valves, "AA", 26, labels, true, &mut memo)
+}
+
+pub fn process_input(lines: &[String]) -> Result<HashMap<String, Valve>, ParseError> {
+    let mut valves = HashMap::new();
+    let re = Regex::new(
+        r"^Valve (?P<valve>[A-Z]+) has flow rate=(?P<flow>\d+); tunnels? leads? to valves? (?P<neighbours>[A-Z]+(, [A-Z]+)*)$",
+    )
+    .expect("Failed to build regex");
+
+    // Every tunnel, with where it was found, so that tunnels to unknown valves can be reported.
+    let mut tunnels = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let caps = re.captures(line).ok_or_else(|| {
+            ParseError::bad_line(
+                index + 1,
+                line,
+                "`Valve XX has flow rate=N; tunnels lead to valves YY, ZZ`",
+            )
+        })?;
+        let label = String::from(caps.name("valve").unwrap().as_str());
+        let flow = parse_num(&caps, "flow", index + 1)?;
+        let neighbours_match = caps.name("neighbours").unwrap();
+        let mut offset = neighbours_match.start();
+        let neighbours = neighbours_match
+            .as_str()
+            .split(", ")
+            .map(|neighbour| {
+                tunnels.push((index + 1, line, offset, neighbour));
+                offset += neighbour.len() + ", ".len();
+                String::from(neighbour)
+            })
+            .collect();
+        let valve = Valve {
+            flow,
+            neighbours,
+            paths: HashMap::new(),
+        };
+        valves.insert(label, valve);
+    }
+
+    for (line_number, line, offset, neighbour) in tunnels {
+        if !valves.contains_key(neighbour) {
+            return Err(ParseError::in_line(
+                line_number,
+                line,
+                offset,
+                "a known valve",
+            ));
+        }
+    }
+
+    // Both parts start from valve AA.
+    if !valves.contains_key("AA") {
+        let last = lines.last().map_or("", |line| line.as_str());
+        return Err(ParseError::in_line(
+            lines.len().max(1),
+            last,
+            last.len(),
+            "a valve `AA`",
+        ));
+    }
+
+    build_paths(&mut valves);
+    Ok(valves)
+}
+
+pub fn part1(valves: &HashMap<String, Valve>) -> u32 {
+    max_pressure(valves)
+}
+
+pub fn part2(valves: &HashMap<String, Valve>) -> u32 {
+    max_pressure_double(valves)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = HashMap<String, Valve>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_non_empty_lines(input, |lines| process_input(lines))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::read_file;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+    use std::collections::{BTreeSet, VecDeque};
+
+    // A small connected cave as (label, flow, neighbours), with no flow at `AA` like the puzzle.
+    fn cave() -> impl Strategy<Value = Vec<(String, u32, BTreeSet<usize>)>> {
+        let labels = ["AA", "BB", "CC", "DD", "EE", "FF"];
+        return (1..labels.len()).prop_flat_map(move |others| {
+            let flows = prop::collection::vec(0..25u32, others);
+            let parents = prop::collection::vec(any::<Index>(), others);
+            let extra = prop::collection::vec(any::<(Index, Index)>(), 0..4);
+            return (flows, parents, extra).prop_map(move |(flows, parents, extra)| {
+                let count = others + 1;
+                let mut neighbours = vec![BTreeSet::new(); count];
+                let mut connect = |a: usize, b: usize| {
+                    if a != b {
+                        neighbours[a].insert(b);
+                        neighbours[b].insert(a);
+                    }
+                };
+                // A tree joins every valve to the ones before it, then a few more tunnels.
+                for (i, parent) in parents.iter().enumerate() {
+                    connect(i + 1, parent.index(i + 1));
+                }
+                for (a, b) in extra {
+                    connect(a.index(count), b.index(count));
+                }
+
+                let flows = [0].into_iter().chain(flows);
+                return labels
+                    .iter()
+                    .zip(flows)
+                    .zip(neighbours)
+                    .map(|((label, flow), neighbours)| (String::from(*label), flow, neighbours))
+                    .collect();
+            });
+        });
+    }
+
+    fn render(cave: &[(String, u32, BTreeSet<usize>)]) -> Vec<String> {
+        return cave
+            .iter()
+            .map(|(label, flow, neighbours)| {
+                let names: Vec<&str> = neighbours.iter().map(|&n| cave[n].0.as_str()).collect();
+                let tunnels = if names.len() == 1 {
+                    "tunnel leads to valve"
+                } else {
+                    "tunnels lead to valves"
+                };
+                return format!(
+                    "Valve {} has flow rate={}; {} {}",
+                    label,
+                    flow,
+                    tunnels,
+                    names.join(", ")
+                );
+            })
+            .collect();
+    }
+
+    // Try every order of opening the valves in `closed`, one worker at a time.
+    fn best_order(
+        cave: &[(String, u32, BTreeSet<usize>)],
+        at: usize,
+        minutes: u32,
+        closed: &[usize],
+    ) -> u32 {
+        let mut distances = vec![u32::MAX; cave.len()];
+        distances[at] = 0;
+        let mut queue = VecDeque::from([at]);
+        while let Some(valve) = queue.pop_front() {
+            for &next in &cave[valve].2 {
+                if distances[next] == u32::MAX {
+                    distances[next] = distances[valve] + 1;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut best = 0;
+        for (i, &valve) in closed.iter().enumerate() {
+            if distances[valve] + 1 >= minutes {
+                continue;
+            }
+            let remaining = minutes - distances[valve] - 1;
+            let mut rest = closed.to_vec();
+            rest.remove(i);
+            best = best.max(remaining * cave[valve].1 + best_order(cave, valve, remaining, &rest));
+        }
+        return best;
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = read_file("test_input");
+        let valves = process_input(&lines).unwrap();
+        let result = part1(&valves);
+        assert_eq!(result, 1651);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = read_file("test_input");
+        let valves = process_input(&lines).unwrap();
+        let result = part2(&valves);
+        assert_eq!(result, 1707);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_roundtrip(cave in cave()) {
+            let valves = process_input(&render(&cave)).unwrap();
+            for (label, flow, neighbours) in &cave {
+                let names: Vec<String> = neighbours.iter().map(|&n| cave[n].0.clone()).collect();
+                prop_assert_eq!(valves[label].flow, *flow);
+                prop_assert_eq!(&valves[label].neighbours, &names);
+                prop_assert_eq!(valves[label].paths.len(), cave.len() - 1);
+            }
+        }
+
+        #[test]
+        fn pressure_matches_every_order(cave in cave()) {
+            let valves = process_input(&render(&cave)).unwrap();
+            let flowing: Vec<usize> = (0..cave.len()).filter(|&v| cave[v].1 > 0).collect();
+            prop_assert_eq!(part1(&valves), best_order(&cave, 0, 30, &flowing));
+
+            // Share the valves out between the human and elephant in every way.
+            let shared = (0..1u32 << flowing.len())
+                .map(|mask| {
+                    let share = |human: bool| -> Vec<usize> {
+                        let chosen = (0..flowing.len()).filter(|i| (mask & (1 << i) != 0) == human);
+                        return chosen.map(|i| flowing[i]).collect();
+                    };
+                    let (human, elephant) = (share(true), share(false));
+                    return best_order(&cave, 0, 26, &human) + best_order(&cave, 0, 26, &elephant);
+                })
+                .max()
+                .unwrap();
+            prop_assert_eq!(part2(&valves), shared);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+use day17::Day17;
+
+aoc_common::bench_main!(Day17, ["input"], sample_size = 10);
@@ -0,0 +1,355 @@
+use aoc_common::{Grid, ParseError, Solution, SolveError};
+use std::cmp;
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+type Point = aoc_common::Point<i64>;
+
+const BOARD_WIDTH: i64 = 7;
+
+#[derive(Copy, Clone, Debug, EnumIter, Eq, Hash, PartialEq)]
+enum Rock {
+    Wide,
+    Cross,
+    Corner,
+    Tall,
+    Square,
+}
+
+#[derive(Debug)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+struct Board {
+    // Row `y` of the grid is the row at height `y`, and row 0 is the floor. Rows are added as the
+    // tower grows.
+    filled: Grid<bool>,
+    max_heights: [i64; 7],
+}
+
+impl Board {
+    fn new() -> Self {
+        let mut filled = Grid::new(BOARD_WIDTH as usize, 0, false);
+        filled.push_row([true; BOARD_WIDTH as usize]);
+
+        Board {
+            filled,
+            max_heights: [0; 7],
+        }
+    }
+
+    // Whether a point is blocked, either by a rock or by the walls or floor.
+    fn is_filled(&self, (x, y): Point) -> bool {
+        if !(0..BOARD_WIDTH).contains(&x) || y < 0 {
+            return true;
+        }
+
+        self.filled
+            .get((x as usize, y as usize))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    fn fill(&mut self, p: Point) {
+        while self.filled.height() <= p.1 as usize {
+            self.filled.push_row([false; BOARD_WIDTH as usize]);
+        }
+        self.filled[(p.0 as usize, p.1 as usize)] = true;
+
+        let col = p.0 as usize;
+        self.max_heights[col] = cmp::max(self.max_heights[col], p.1);
+    }
+}
+
+fn get_rock_coords(rock: Rock) -> &'static [Point] {
+    static WIDE: [Point; 4] = [(0, 0), (1, 0), (2, 0), (3, 0)];
+    static CROSS: [Point; 5] = [(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)];
+    static CORNER: [Point; 5] = [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)];
+    static TALL: [Point; 4] = [(0, 0), (0, 1), (0, 2), (0, 3)];
+    static SQUARE: [Point; 4] = [(0, 0), (1, 0), (0, 1), (1, 1)];
+
+    match rock {
+        Rock::Wide => &WIDE,
+        Rock::Cross => &CROSS,
+        Rock::Corner => &CORNER,
+        Rock::Tall => &TALL,
+        Rock::Square => &SQUARE,
+    }
+}
+
+fn drop_rock<'a>(
+    rock: Rock,
+    board: &Board,
+    jets: &mut impl Iterator<Item = (usize, &'a Jet)>,
+) -> Vec<Point> {
+    let start_height = board.max_heights.iter().max().unwrap() + 4;
+    let mut origin = (2, start_height);
+
+    let mut dropping = false;
+    let mut prev_points = Vec::new();
+    loop {
+        let new_origin = if dropping {
+            // The rock falls
+            (origin.0, origin.1 - 1)
+        } else {
+            // A jet blows the rock
+            let dir = jets.next().unwrap();
+            match dir.1 {
+                Jet::Left => (origin.0 - 1, origin.1),
+                Jet::Right => (origin.0 + 1, origin.1),
+            }
+        };
+
+        let candidate_points: Vec<Point> = get_rock_coords(rock)
+            .iter()
+            .map(|c| (c.0 + new_origin.0, c.1 + new_origin.1))
+            .collect();
+        let can_move = candidate_points.iter().all(|&c| !board.is_filled(c));
+
+        if can_move {
+            origin = new_origin;
+            prev_points = candidate_points;
+        } else if dropping {
+            return prev_points;
+        }
+
+        dropping = !dropping;
+    }
+}
+
+fn drop_rocks(board: &mut Board, jets: &[Jet], count: i64) {
+    let mut jets_iter = jets.iter().cycle().enumerate();
+    let mut rocks = Rock::iter().cycle();
+    for _ in 0..count {
+        let new_points = drop_rock(rocks.next().unwrap(), board, &mut jets_iter);
+
+        for p in new_points {
+            board.fill(p);
+        }
+    }
+}
+
+// Every position a rock could be moved to, found by flooding left, right and down from the row
+// rocks appear in, looking no more than `depth` rows below it. Each rock's positions are a bitmask
+// of x positions per row, from the row rocks appear in downwards. Every move a falling rock tries
+// within those rows is either into one of these positions or is blocked.
+fn reachable_positions(board: &Board, top: i64, depth: usize) -> Vec<Vec<u8>> {
+    let start_y = top + 4;
+    Rock::iter()
+        .map(|rock| {
+            let fits = |(x, y): Point| {
+                get_rock_coords(rock)
+                    .iter()
+                    .all(|c| !board.is_filled((c.0 + x, c.1 + y)))
+            };
+
+            let mut rows: Vec<u8> = Vec::new();
+            let mut queue: Vec<Point> = Vec::new();
+            let visit = |rows: &mut Vec<u8>, (x, y): Point| {
+                let row = (start_y - y) as usize;
+                if row > depth || !fits((x, y)) {
+                    return false;
+                }
+                if rows.len() <= row {
+                    rows.resize(row + 1, 0);
+                }
+                let seen = rows[row] & (1 << x) != 0;
+                rows[row] |= 1 << x;
+                !seen
+            };
+
+            for x in 0..BOARD_WIDTH {
+                if visit(&mut rows, (x, start_y)) {
+                    queue.push((x, start_y));
+                }
+            }
+            while let Some(pos) = queue.pop() {
+                for (x_inc, y_inc) in [(-1, 0), (0, -1), (1, 0)] {
+                    let n = (pos.0 + x_inc, pos.1 + y_inc);
+                    if (0..BOARD_WIDTH).contains(&n.0) && visit(&mut rows, n) {
+                        queue.push(n);
+                    }
+                }
+            }
+
+            rows
+        })
+        .collect()
+}
+
+fn find_height(board: &mut Board, jets: &[Jet], drop_count: i64) -> i64 {
+    // Previous states at the start of dropping a rock - maps rock type, starting jet index and the
+    // positions rocks can reach to the number of rocks that had been dropped.
+    let mut states: HashMap<(Rock, usize, Vec<Vec<u8>>), i64> = HashMap::new();
+
+    // The deepest any rock has tried to move so far, in rows below the row it appeared in. States
+    // only compare the positions down to here, as a column that never fills would otherwise leave
+    // the whole tower reachable, and the states would never repeat. If no rock looks any deeper
+    // between two equal states, the drops in between repeat exactly, so the states are forgotten
+    // whenever a rock does.
+    let mut depth = 0;
+
+    // The height of the board after each drop, starting with the empty board.
+    let mut heights: Vec<i64> = vec![0];
+
+    let rocks = Rock::iter().cycle();
+    let mut jets_iter = jets.iter().cycle().enumerate().peekable();
+    for (drops, rock) in (0..).zip(rocks) {
+        let max_height = *board.max_heights.iter().max().unwrap();
+        if drops == drop_count {
+            // Got there before the drops started repeating.
+            return max_height;
+        }
+
+        // Check for a cycle, where everything that affects the next drops is the same as before.
+        let next_jet_idx = jets_iter.peek().unwrap().0 % jets.len();
+        let state = (
+            rock,
+            next_jet_idx,
+            reachable_positions(board, max_height, depth),
+        );
+        if let Some(&cycle_start) = states.get(&state) {
+            let cycle_length = drops - cycle_start;
+            let cycle_height = max_height - heights[cycle_start as usize];
+
+            // Skip as many whole cycles as we can, then the height added by the rest of the drops
+            // is the height added by the start of the cycle.
+            let cycle_count = (drop_count - cycle_start) / cycle_length;
+            let cycle_rem = (drop_count - cycle_start) % cycle_length;
+            return heights[(cycle_start + cycle_rem) as usize] + cycle_count * cycle_height;
+        }
+        states.insert(state, drops);
+
+        // Drop the rock. It stopped because it couldn't fall into the row below where it landed.
+        let new_points = drop_rock(rock, board, &mut jets_iter);
+        let lowest = new_points.iter().map(|p| p.1).min().unwrap();
+        let rock_depth = (max_height + 4 - (lowest - 1)) as usize;
+        if rock_depth > depth {
+            depth = rock_depth;
+            states.clear();
+        }
+
+        for p in new_points {
+            board.fill(p);
+        }
+        heights.push(*board.max_heights.iter().max().unwrap());
+    }
+
+    unreachable!("The drops never end")
+}
+
+pub fn part1(jets: &[Jet]) -> i64 {
+    let mut board = Board::new();
+    drop_rocks(&mut board, jets, 2022);
+
+    *board.max_heights.iter().max().unwrap()
+}
+
+pub fn part2(jets: &[Jet]) -> i64 {
+    let mut board = Board::new();
+    find_height(&mut board, jets, 1000000000000)
+}
+
+pub fn parse_input(raw_jet: &str) -> Result<Vec<Jet>, ParseError> {
+    if raw_jet.is_empty() {
+        return Err(ParseError::in_line(1, raw_jet, 0, "a jet `<` or `>`"));
+    }
+
+    raw_jet
+        .char_indices()
+        .map(|(offset, c)| match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(ParseError::in_line(1, raw_jet, offset, "a jet `<` or `>`")),
+        })
+        .collect()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Jet>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input.trim_end())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn height_after(jets: &[Jet], count: i64) -> i64 {
+        let mut board = Board::new();
+        drop_rocks(&mut board, jets, count);
+        *board.max_heights.iter().max().unwrap()
+    }
+
+    #[test]
+    fn pt1_test() {
+        let jets = parse_input(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
+        assert_eq!(part1(&jets), 3068);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let jets = parse_input(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
+        assert_eq!(part2(&jets), 1514285714288);
+    }
+
+    #[test]
+    fn deep_gap_test() {
+        // The left of the tower grows while the right stays empty, then every so often a rock
+        // falls hundreds of rows down the gap. Comparing a fixed number of rows, like the top
+        // 256, finds a cycle that isn't there.
+        let jets = parse_input(&format!("{}{}", "<".repeat(1000), ">".repeat(400))).unwrap();
+        let mut board = Board::new();
+        assert_eq!(find_height(&mut board, &jets, 4000), 8887);
+        assert_eq!(height_after(&jets, 4000), 8887);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_roundtrip(raw in "[<>]{1,50}") {
+            let jets = parse_input(&raw).unwrap();
+            let printed: String = jets
+                .iter()
+                .map(|jet| match jet {
+                    Jet::Left => '<',
+                    Jet::Right => '>',
+                })
+                .collect();
+            prop_assert_eq!(printed, raw);
+        }
+
+        #[test]
+        fn height_bounded_by_rocks(raw in "[<>]{1,50}", count in 0..200i64) {
+            // The rocks are 1, 3, 3, 4 and 2 tall, and can't stack any higher than that.
+            let tallest: i64 = [1, 3, 3, 4, 2].iter().cycle().take(count as usize).sum();
+            let height = height_after(&parse_input(&raw).unwrap(), count);
+            prop_assert!(height <= tallest);
+            prop_assert!(count == 0 || height > 0);
+        }
+
+        #[test]
+        fn cycle_height_matches_simulation(raw in "[<>]{1,20}", count in 2000..3000i64) {
+            let jets = parse_input(&raw).unwrap();
+            let mut board = Board::new();
+            prop_assert_eq!(find_height(&mut board, &jets, count), height_after(&jets, count));
+        }
+    }
+}
@@ -0,0 +1,3 @@
+use day18::Day18;
+
+aoc_common::bench_main!(Day18, ["input"]);
@@ -0,0 +1,340 @@
+use aoc_common::{parse_non_empty_lines, ParseError, Point3, Solution, SolveError};
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+
+type Cube = Point3;
+
+// Total surface area (both internal and external) for a droplet.
+fn surface_area(droplet: &HashSet<Cube>) -> usize {
+    let mut area = 0;
+
+    for &(x, y, z) in droplet {
+        area += [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .iter()
+        .map(|&(x_inc, y_inc, z_inc)| {
+            let neighbour = (x + x_inc, y + y_inc, z + z_inc);
+            usize::from(!droplet.contains(&neighbour))
+        })
+        .sum::<usize>();
+    }
+
+    area
+}
+
+fn is_external(
+    pos: (i32, i32, i32),
+    droplet: &HashSet<Cube>,
+    mins: (i32, i32, i32),
+    maxs: (i32, i32, i32),
+    memo: &mut HashMap<Cube, bool>,
+) -> bool {
+    // DFS until we either reach a node outside the bounds of the droplet, in which case
+    // this is an 'external' node, or until we run out of non-droplet nodes, in which case
+    // this is an 'internal' node.
+    let mut external = false;
+    let mut visited = HashSet::from([pos]);
+    let mut queue = vec![pos];
+    while let Some(cur_pos) = queue.pop() {
+        if droplet.contains(&cur_pos) {
+            // Stop when we hit a cube that is part of the droplet.
+            continue;
+        }
+
+        if memo.contains_key(&cur_pos) {
+            external = memo[&cur_pos];
+            break;
+        }
+
+        if !(((mins.0)..=maxs.0).contains(&cur_pos.0)
+            && ((mins.1)..=maxs.1).contains(&cur_pos.1)
+            && ((mins.2)..=maxs.2).contains(&cur_pos.2))
+        {
+            // This position is outside the bounds, therefore must
+            // be external, and all the positions we visited to reach
+            // here must be too.
+            external = true;
+            break;
+        }
+
+        let neighbours = [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .iter()
+        .map(|&(x_inc, y_inc, z_inc)| (cur_pos.0 + x_inc, cur_pos.1 + y_inc, cur_pos.2 + z_inc))
+        .filter(|pos| !visited.contains(pos))
+        .collect::<Vec<_>>();
+
+        for n in neighbours {
+            visited.insert(n);
+            queue.push(n);
+        }
+    }
+
+    // Update the cache for all the nodes we visited - they are either all external or all internal.
+    for p in visited {
+        if !droplet.contains(&p) {
+            memo.insert(p, external);
+        }
+    }
+
+    external
+}
+
+fn find_droplet_bounds(droplet: &HashSet<Cube>) -> ((i32, i32, i32), (i32, i32, i32)) {
+    // Find the bounding box of the droplet. Pick a random point in the droplet to start the fold at.
+    let start_point = *droplet.iter().next().unwrap();
+    droplet
+        .iter()
+        .fold((start_point, start_point), |acc, &(x, y, z)| {
+            let mins = acc.0;
+            let maxs = acc.1;
+            let new_mins = (
+                cmp::min(mins.0, x),
+                cmp::min(mins.1, y),
+                cmp::min(mins.2, z),
+            );
+            let new_maxs = (
+                cmp::max(maxs.0, x),
+                cmp::max(maxs.1, y),
+                cmp::max(maxs.2, z),
+            );
+            (new_mins, new_maxs)
+        })
+}
+
+fn external_surface_area(droplet: &HashSet<Cube>) -> usize {
+    let (mins, maxs) = find_droplet_bounds(droplet);
+
+    let mut area = 0;
+    let mut memo = HashMap::new();
+    for &(x, y, z) in droplet {
+        area += [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .iter()
+        .map(|&(x_inc, y_inc, z_inc)| {
+            let neighbour = (x + x_inc, y + y_inc, z + z_inc);
+            usize::from(is_external(neighbour, droplet, mins, maxs, &mut memo))
+        })
+        .sum::<usize>();
+    }
+
+    area
+}
+
+pub fn part1(droplet: &HashSet<Cube>) -> usize {
+    surface_area(droplet)
+}
+
+pub fn part2(droplet: &HashSet<Cube>) -> usize {
+    external_surface_area(droplet)
+}
+
+fn parse_cube(line_number: usize, line: &str) -> Result<Cube, ParseError> {
+    let mut coords = [0; 3];
+    let mut parts = line.split(',');
+    let mut offset = 0;
+
+    for coord in coords.iter_mut() {
+        let part = parts.next().unwrap_or("");
+        *coord = part.parse::<i32>().map_err(|_| {
+            ParseError::in_line(line_number, line, offset.min(line.len()), "a coordinate")
+        })?;
+        offset += part.len() + 1;
+    }
+
+    if offset <= line.len() {
+        return Err(ParseError::in_line(
+            line_number,
+            line,
+            offset - 1,
+            "end of line",
+        ));
+    }
+
+    Ok((coords[0], coords[1], coords[2]))
+}
+
+pub fn parse_lines(lines: &[String]) -> Result<HashSet<Cube>, ParseError> {
+    if lines.is_empty() {
+        return Err(ParseError::new(1, 1, "a cube like `1,2,3`", ""));
+    }
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, l)| parse_cube(index + 1, l))
+        .collect()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<Cube>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_non_empty_lines(input, |lines| parse_lines(lines))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const FACES: [Cube; 6] = [
+        (1, 0, 0),
+        (-1, 0, 0),
+        (0, 1, 0),
+        (0, -1, 0),
+        (0, 0, 1),
+        (0, 0, -1),
+    ];
+
+    fn droplet() -> impl Strategy<Value = HashSet<Cube>> {
+        prop::collection::hash_set((0..5, 0..5, 0..5), 1..60)
+    }
+
+    fn render(droplet: &HashSet<Cube>) -> Vec<String> {
+        droplet
+            .iter()
+            .map(|(x, y, z)| format!("{},{},{}", x, y, z))
+            .collect()
+    }
+
+    // Flood the box around the droplet from one corner, and count the faces the water touches.
+    fn flooded_faces(droplet: &HashSet<Cube>) -> usize {
+        let bounds = -1..=5;
+        let mut water = HashSet::from([(-1, -1, -1)]);
+        let mut queue = vec![(-1, -1, -1)];
+        let mut faces = 0;
+        while let Some((x, y, z)) = queue.pop() {
+            for (dx, dy, dz) in FACES {
+                let next = (x + dx, y + dy, z + dz);
+                if droplet.contains(&next) {
+                    faces += 1;
+                } else if [next.0, next.1, next.2].iter().all(|c| bounds.contains(c))
+                    && water.insert(next)
+                {
+                    queue.push(next);
+                }
+            }
+        }
+        faces
+    }
+
+    #[test]
+    fn hollow_cube_test() {
+        // A 3x3x3 cube with the middle missing has 6 faces on the inside.
+        let mut droplet = HashSet::new();
+        for x in 0..3 {
+            for y in 0..3 {
+                for z in 0..3 {
+                    droplet.insert((x, y, z));
+                }
+            }
+        }
+        droplet.remove(&(1, 1, 1));
+        assert_eq!(part1(&droplet), 60);
+        assert_eq!(part2(&droplet), 54);
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = vec![
+            String::from("2,2,2"),
+            String::from("1,2,2"),
+            String::from("3,2,2"),
+            String::from("2,1,2"),
+            String::from("2,3,2"),
+            String::from("2,2,1"),
+            String::from("2,2,3"),
+            String::from("2,2,4"),
+            String::from("2,2,6"),
+            String::from("1,2,5"),
+            String::from("3,2,5"),
+            String::from("2,1,5"),
+            String::from("2,3,5"),
+        ];
+        let droplet = parse_lines(&lines).unwrap();
+        assert_eq!(part1(&droplet), 64);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = vec![
+            String::from("2,2,2"),
+            String::from("1,2,2"),
+            String::from("3,2,2"),
+            String::from("2,1,2"),
+            String::from("2,3,2"),
+            String::from("2,2,1"),
+            String::from("2,2,3"),
+            String::from("2,2,4"),
+            String::from("2,2,6"),
+            String::from("1,2,5"),
+            String::from("3,2,5"),
+            String::from("2,1,5"),
+            String::from("2,3,5"),
+        ];
+        let droplet = parse_lines(&lines).unwrap();
+        assert_eq!(part2(&droplet), 58);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = Day18::parse("\n").unwrap_err();
+        assert_eq!(err.expected, "a cube like `1,2,3`");
+    }
+
+    proptest! {
+        #[test]
+        fn parse_roundtrip(droplet in droplet()) {
+            prop_assert_eq!(parse_lines(&render(&droplet)).unwrap(), droplet);
+        }
+
+        #[test]
+        fn area_counts_shared_faces(droplet in droplet()) {
+            // Every pair of touching cubes hides one face of each.
+            let touching = droplet
+                .iter()
+                .flat_map(|&(x, y, z)| FACES.iter().map(move |(dx, dy, dz)| (x + dx, y + dy, z + dz)))
+                .filter(|neighbour| droplet.contains(neighbour))
+                .count();
+            prop_assert_eq!(part1(&droplet), 6 * droplet.len() - touching);
+        }
+
+        #[test]
+        fn external_area_matches_flood_fill(droplet in droplet()) {
+            let external = part2(&droplet);
+            prop_assert_eq!(external, flooded_faces(&droplet));
+            prop_assert!(external <= part1(&droplet));
+        }
+    }
+}
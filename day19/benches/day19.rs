@@ -0,0 +1,3 @@
+use day19::Day19;
+
+aoc_common::bench_main!(Day19, ["input"], sample_size = 10);
@@ -0,0 +1,433 @@
+use aoc_common::{parse_non_empty_lines, parse_num, ParseError, Solution, SolveError};
+use regex::Regex;
+
+#[derive(Clone, Copy, Debug)]
+struct Cost {
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Blueprint {
+    index: u32,
+    ore_robot: Cost,
+    clay_robot: Cost,
+    obsidian_robot: Cost,
+    geode_robot: Cost,
+}
+
+impl Blueprint {
+    fn max_costs(&self) -> Cost {
+        Cost {
+            ore: *[
+                self.ore_robot.ore,
+                self.clay_robot.ore,
+                self.obsidian_robot.ore,
+                self.geode_robot.ore,
+            ]
+            .iter()
+            .max()
+            .unwrap(),
+            clay: *[
+                self.ore_robot.clay,
+                self.clay_robot.clay,
+                self.obsidian_robot.clay,
+                self.geode_robot.clay,
+            ]
+            .iter()
+            .max()
+            .unwrap(),
+            obsidian: *[
+                self.ore_robot.obsidian,
+                self.clay_robot.obsidian,
+                self.obsidian_robot.obsidian,
+                self.geode_robot.obsidian,
+            ]
+            .iter()
+            .max()
+            .unwrap(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct State {
+    ore_robots: u32,
+    clay_robots: u32,
+    obsidian_robots: u32,
+    geode_robots: u32,
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geodes: u32,
+}
+
+impl State {
+    fn new() -> Self {
+        State {
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geodes: 0,
+        }
+    }
+
+    // The number of a given price we can afford.
+    fn can_afford(&self, cost: Cost) -> bool {
+        self.ore >= cost.ore && self.clay >= cost.clay && self.obsidian >= cost.obsidian
+    }
+
+    fn mine(&mut self) {
+        self.ore += self.ore_robots;
+        self.clay += self.clay_robots;
+        self.obsidian += self.obsidian_robots;
+        self.geodes += self.geode_robots;
+    }
+
+    fn build(&mut self, cost: Cost) {
+        self.ore -= cost.ore;
+        self.clay -= cost.clay;
+        self.obsidian -= cost.obsidian;
+    }
+}
+
+fn run_state(state: State, prev_state: Option<&State>, blueprint: &Blueprint, minutes: u32) -> u32 {
+    if minutes == 1 {
+        let mut new_state = state;
+        new_state.mine();
+        return new_state.geodes;
+    }
+
+    let mut branches = vec![];
+
+    // If we could build a particular robot last minute, but chose not to, then it doesn't make sense to continue down a branch where we build one this minute,
+    // as there's no way it can lead to a better solution than just building that robot earlier.
+    let built_robot = prev_state
+        .map(|p| {
+            p.geode_robots != state.geode_robots
+                || p.obsidian_robots != state.obsidian_robots
+                || p.clay_robots != state.clay_robots
+                || p.ore_robots != state.ore_robots
+        })
+        .unwrap_or(false);
+    let ignored_geode_robot = prev_state
+        .map(|p| p.can_afford(blueprint.geode_robot) && !built_robot)
+        .unwrap_or(false);
+    let ignored_obsidian_robot = prev_state
+        .map(|p| p.can_afford(blueprint.obsidian_robot) && !built_robot)
+        .unwrap_or(false);
+    let ignored_clay_robot = prev_state
+        .map(|p| p.can_afford(blueprint.clay_robot) && !built_robot)
+        .unwrap_or(false);
+    let ignored_ore_robot = prev_state
+        .map(|p| p.can_afford(blueprint.ore_robot) && !built_robot)
+        .unwrap_or(false);
+
+    // If we are producing as much of a particular resource as it costs to buy the most expensive usage of that resource, there is no point building any more.
+    let max_obsidion_production = state.obsidian_robots >= blueprint.max_costs().obsidian;
+    let max_clay_production = state.clay_robots >= blueprint.max_costs().clay;
+    let max_ore_production = state.ore_robots >= blueprint.max_costs().ore;
+
+    if state.can_afford(blueprint.geode_robot) && !ignored_geode_robot {
+        let mut new_state = state;
+        new_state.mine();
+        new_state.build(blueprint.geode_robot);
+        new_state.geode_robots += 1;
+        branches.push(run_state(new_state, Some(&state), blueprint, minutes - 1));
+    }
+
+    if state.can_afford(blueprint.obsidian_robot)
+        && !ignored_obsidian_robot
+        && !max_obsidion_production
+    {
+        let mut new_state = state;
+        new_state.mine();
+        new_state.build(blueprint.obsidian_robot);
+        new_state.obsidian_robots += 1;
+        branches.push(run_state(new_state, Some(&state), blueprint, minutes - 1));
+    }
+
+    if state.can_afford(blueprint.clay_robot) && !ignored_clay_robot && !max_clay_production {
+        let mut new_state = state;
+        new_state.mine();
+        new_state.build(blueprint.clay_robot);
+        new_state.clay_robots += 1;
+        branches.push(run_state(new_state, Some(&state), blueprint, minutes - 1));
+    }
+
+    if state.can_afford(blueprint.ore_robot) && !ignored_ore_robot && !max_ore_production {
+        let mut new_state = state;
+        new_state.mine();
+        new_state.build(blueprint.ore_robot);
+        new_state.ore_robots += 1;
+        branches.push(run_state(new_state, Some(&state), blueprint, minutes - 1));
+    }
+
+    let mut new_state = state;
+    new_state.mine();
+    branches.push(run_state(new_state, Some(&state), blueprint, minutes - 1));
+
+    *branches.iter().max().unwrap()
+}
+
+pub fn part1(blueprints: &[Blueprint]) -> u32 {
+    blueprints
+        .iter()
+        .map(|b| b.index * run_state(State::new(), None, b, 24))
+        .sum()
+}
+
+pub fn part2(blueprints: &[Blueprint]) -> u32 {
+    blueprints
+        .iter()
+        .take(3)
+        .map(|b| run_state(State::new(), None, b, 32))
+        .product()
+}
+
+fn parse_line(line_number: usize, line: &str) -> Result<Blueprint, ParseError> {
+    let re = Regex::new(
+        r"^Blueprint (?P<index>\d+): Each ore robot costs (?P<ore_robot_ore>\d+) ore. Each clay robot costs (?P<clay_robot_ore>\d+) ore. Each obsidian robot costs (?P<obs_robot_ore>\d+) ore and (?P<obs_robot_clay>\d+) clay. Each geode robot costs (?P<geode_robot_ore>\d+) ore and (?P<geode_robot_obs>\d+) obsidian.$").expect("Failed to compile regex");
+
+    let caps = re.captures(line).ok_or_else(|| {
+        ParseError::bad_line(
+            line_number,
+            line,
+            "a blueprint like `Blueprint N: Each ore robot costs N ore. ...`",
+        )
+    })?;
+    Ok(Blueprint {
+        index: parse_num(&caps, "index", line_number)?,
+        ore_robot: Cost {
+            ore: parse_num(&caps, "ore_robot_ore", line_number)?,
+            clay: 0,
+            obsidian: 0,
+        },
+        clay_robot: Cost {
+            ore: parse_num(&caps, "clay_robot_ore", line_number)?,
+            clay: 0,
+            obsidian: 0,
+        },
+        obsidian_robot: Cost {
+            ore: parse_num(&caps, "obs_robot_ore", line_number)?,
+            clay: parse_num(&caps, "obs_robot_clay", line_number)?,
+            obsidian: 0,
+        },
+        geode_robot: Cost {
+            ore: parse_num(&caps, "geode_robot_ore", line_number)?,
+            clay: 0,
+            obsidian: parse_num(&caps, "geode_robot_obs", line_number)?,
+        },
+    })
+}
+
+pub fn parse_lines(lines: &[String]) -> Result<Vec<Blueprint>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, l)| parse_line(index + 1, l))
+        .collect()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_non_empty_lines(input, |lines| parse_lines(lines))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    // Cheap blueprints, so that geodes get cracked within a few minutes.
+    fn blueprint() -> impl Strategy<Value = Blueprint> {
+        let ore = || 1..5u32;
+        (1..30u32, ore(), ore(), (ore(), 1..6u32), (ore(), 1..6u32)).prop_map(
+            |(index, ore_robot, clay_robot, obsidian_robot, geode_robot)| Blueprint {
+                index,
+                ore_robot: Cost {
+                    ore: ore_robot,
+                    clay: 0,
+                    obsidian: 0,
+                },
+                clay_robot: Cost {
+                    ore: clay_robot,
+                    clay: 0,
+                    obsidian: 0,
+                },
+                obsidian_robot: Cost {
+                    ore: obsidian_robot.0,
+                    clay: obsidian_robot.1,
+                    obsidian: 0,
+                },
+                geode_robot: Cost {
+                    ore: geode_robot.0,
+                    clay: 0,
+                    obsidian: geode_robot.1,
+                },
+            },
+        )
+    }
+
+    fn render(b: &Blueprint) -> String {
+        format!(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+            b.index,
+            b.ore_robot.ore,
+            b.clay_robot.ore,
+            b.obsidian_robot.ore,
+            b.obsidian_robot.clay,
+            b.geode_robot.ore,
+            b.geode_robot.obsidian
+        )
+    }
+
+    type StateKey = (u32, u32, u32, u32, u32, u32, u32, u32);
+
+    fn key(s: &State) -> StateKey {
+        (
+            s.ore_robots,
+            s.clay_robots,
+            s.obsidian_robots,
+            s.geode_robots,
+            s.ore,
+            s.clay,
+            s.obsidian,
+            s.geodes,
+        )
+    }
+
+    // Every choice at every minute, without any pruning.
+    fn exhaustive(
+        state: State,
+        blueprint: &Blueprint,
+        minutes: u32,
+        memo: &mut HashMap<(StateKey, u32), u32>,
+    ) -> u32 {
+        if minutes == 0 {
+            return state.geodes;
+        }
+        if let Some(&best) = memo.get(&(key(&state), minutes)) {
+            return best;
+        }
+
+        let mut waiting = state;
+        waiting.mine();
+        let mut best = exhaustive(waiting, blueprint, minutes - 1, memo);
+
+        let mut build = |cost: Cost, add_robot: fn(&mut State)| {
+            if state.can_afford(cost) {
+                let mut building = waiting;
+                building.build(cost);
+                add_robot(&mut building);
+                best = best.max(exhaustive(building, blueprint, minutes - 1, memo));
+            }
+        };
+        build(blueprint.ore_robot, |s| s.ore_robots += 1);
+        build(blueprint.clay_robot, |s| s.clay_robots += 1);
+        build(blueprint.obsidian_robot, |s| s.obsidian_robots += 1);
+        build(blueprint.geode_robot, |s| s.geode_robots += 1);
+
+        memo.insert((key(&state), minutes), best);
+        best
+    }
+
+    fn example_blueprints() -> Vec<Blueprint> {
+        vec![
+            Blueprint {
+                index: 1,
+                ore_robot: Cost {
+                    ore: 4,
+                    clay: 0,
+                    obsidian: 0,
+                },
+                clay_robot: Cost {
+                    ore: 2,
+                    clay: 0,
+                    obsidian: 0,
+                },
+                obsidian_robot: Cost {
+                    ore: 3,
+                    clay: 14,
+                    obsidian: 0,
+                },
+                geode_robot: Cost {
+                    ore: 2,
+                    clay: 0,
+                    obsidian: 7,
+                },
+            },
+            Blueprint {
+                index: 2,
+                ore_robot: Cost {
+                    ore: 2,
+                    clay: 0,
+                    obsidian: 0,
+                },
+                clay_robot: Cost {
+                    ore: 3,
+                    clay: 0,
+                    obsidian: 0,
+                },
+                obsidian_robot: Cost {
+                    ore: 3,
+                    clay: 8,
+                    obsidian: 0,
+                },
+                geode_robot: Cost {
+                    ore: 3,
+                    clay: 0,
+                    obsidian: 12,
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn part1_test() {
+        assert_eq!(part1(&example_blueprints()), 33);
+    }
+
+    #[test]
+    #[ignore = "takes minutes, run with `cargo test --release -- --ignored`"]
+    fn part2_test() {
+        assert_eq!(part2(&example_blueprints()), 56 * 62);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_roundtrip(blueprints in prop::collection::vec(blueprint(), 1..5)) {
+            let lines: Vec<String> = blueprints.iter().map(render).collect();
+            let parsed = parse_lines(&lines).unwrap();
+            let rendered: Vec<String> = parsed.iter().map(render).collect();
+            prop_assert_eq!(rendered, lines);
+        }
+
+        #[test]
+        fn pruned_search_matches_exhaustive(blueprint in blueprint(), minutes in 1..14u32) {
+            let expected = exhaustive(State::new(), &blueprint, minutes, &mut HashMap::new());
+            prop_assert_eq!(run_state(State::new(), None, &blueprint, minutes), expected);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+use day2::Day2;
+
+aoc_common::bench_main!(Day2, ["input"]);
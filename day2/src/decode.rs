@@ -0,0 +1,158 @@
+use crate::game::{CyclicGame, Shape, Turn};
+use itertools::Itertools;
+
+// What the letters in the second column of a guide stand for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Meaning {
+    // The shape to play.
+    Shape,
+
+    // How to respond to their shape, see `CyclicGame::respond`. With three shapes, that's
+    // whether to lose, draw or win.
+    Response,
+}
+
+// One way of reading the second column, where letter `i` stands for the shape or response with
+// index `order[i]`, and the score following the guide would get when read that way.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Interpretation {
+    pub meaning: Meaning,
+    pub order: Vec<usize>,
+    pub score: u32,
+}
+
+impl Interpretation {
+    // Show what each letter stands for: the score for playing a shape, or how many shapes round
+    // from theirs a response is.
+    pub fn describe(&self, game: &CyclicGame) -> String {
+        let half = (game.size() / 2) as i64;
+        let meanings: Vec<String> = game
+            .our_letters()
+            .iter()
+            .zip(&self.order)
+            .map(|(letter, &index)| match self.meaning {
+                Meaning::Shape => format!("{}={}", letter, Shape(index).score()),
+                Meaning::Response => format!("{}={:+}", letter, index as i64 - half),
+            })
+            .collect();
+        return meanings.join(" ");
+    }
+}
+
+// Score a guide under every reading of its second column, as each ordering of shapes and each
+// ordering of responses, in that order.
+pub fn interpretations(game: &CyclicGame, turns: &[Turn]) -> Vec<Interpretation> {
+    let size = game.size();
+
+    // Only how often each pair of letters turns up matters, so count those first rather than
+    // going through the guide for every reading.
+    let mut counts = vec![vec![0u32; size]; size];
+    for turn in turns {
+        counts[turn.them.0][turn.code] += 1;
+    }
+
+    let mut results = Vec::new();
+    for meaning in [Meaning::Shape, Meaning::Response] {
+        for order in (0..size).permutations(size) {
+            let mut score = 0;
+            for (them, row) in counts.iter().enumerate() {
+                for (code, &count) in row.iter().enumerate() {
+                    let them = Shape(them);
+                    let us = match meaning {
+                        Meaning::Shape => Shape(order[code]),
+                        Meaning::Response => game.respond(them, order[code]),
+                    };
+                    score += count * game.score_turn(them, us);
+                }
+            }
+
+            results.push(Interpretation {
+                meaning,
+                order,
+                score,
+            });
+        }
+    }
+
+    return results;
+}
+
+// The reading with the highest score, the first one if there's a tie.
+pub fn highest(interpretations: &[Interpretation]) -> Option<&Interpretation> {
+    return interpretations
+        .iter()
+        .reduce(|best, next| if next.score > best.score { next } else { best });
+}
+
+// The reading with the lowest score, the first one if there's a tie.
+pub fn lowest(interpretations: &[Interpretation]) -> Option<&Interpretation> {
+    return interpretations.iter().min_by_key(|i| i.score);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_lines;
+    use crate::{part1, part2};
+    use proptest::prelude::*;
+
+    fn example() -> Vec<Turn> {
+        let lines = vec![
+            String::from("A Y"),
+            String::from("B X"),
+            String::from("C Z"),
+        ];
+        return parse_lines(&lines).unwrap();
+    }
+
+    #[test]
+    fn example_test() {
+        let game = CyclicGame::rock_paper_scissors();
+        let turns = example();
+        let all = interpretations(&game, &turns);
+        assert_eq!(all.len(), 12);
+
+        // The puzzle's own readings are the first of each kind.
+        assert_eq!(all[0].score, part1(&turns));
+        assert_eq!(all[0].describe(&game), "X=1 Y=2 Z=3");
+        assert_eq!(all[6].score, part2(&turns));
+        assert_eq!(all[6].describe(&game), "X=-1 Y=+0 Z=+1");
+
+        // Reading every letter as a win, with the shape scores as high as possible.
+        let best = highest(&all).unwrap();
+        assert_eq!(best.meaning, Meaning::Shape);
+        assert_eq!(best.score, (2 + 6) + (3 + 6) + (1 + 6));
+        assert_eq!(lowest(&all).unwrap().score, 6);
+    }
+
+    fn turns() -> impl Strategy<Value = Vec<Turn>> {
+        let turn = (0..3usize, 0..3usize).prop_map(|(them, code)| Turn {
+            them: Shape(them),
+            code,
+        });
+        return prop::collection::vec(turn, 0..50);
+    }
+
+    proptest! {
+        #[test]
+        fn scores_match_rescoring(turns in turns()) {
+            let game = CyclicGame::rock_paper_scissors();
+            let all = interpretations(&game, &turns);
+
+            for interpretation in &all {
+                let relabelled: Vec<Turn> = turns
+                    .iter()
+                    .map(|turn| Turn { them: turn.them, code: interpretation.order[turn.code] })
+                    .collect();
+                let expected = match interpretation.meaning {
+                    Meaning::Shape => game.score_as_shapes(&relabelled),
+                    Meaning::Response => game.score_as_responses(&relabelled),
+                };
+                prop_assert_eq!(interpretation.score, expected);
+            }
+
+            let (high, low) = (highest(&all).unwrap(), lowest(&all).unwrap());
+            prop_assert!(all.iter().all(|i| low.score <= i.score && i.score <= high.score));
+        }
+    }
+}
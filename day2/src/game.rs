@@ -0,0 +1,296 @@
+use aoc_common::ParseError;
+use std::collections::HashSet;
+
+// A shape, by its position in the game's cycle from 0. Playing it scores one more than that.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Shape(pub usize);
+
+impl Shape {
+    pub fn score(self) -> u32 {
+        return self.0 as u32 + 1;
+    }
+}
+
+// Values are the score for that result.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Result {
+    Win = 6,
+    Draw = 3,
+    Lose = 0,
+}
+
+// One line of a strategy guide: the shape they play, and the index of the letter in the second
+// column, which can be read either as a shape or as how to respond (see `CyclicGame::respond`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Turn {
+    pub them: Shape,
+    pub code: usize,
+}
+
+// A game like rock-paper-scissors with an odd number of shapes arranged in a cycle, where each
+// shape beats the half of the other shapes just before it, and loses to the half just after it.
+// Each shape has a letter for when they play it in the first column of a guide, and one for the
+// second column.
+#[derive(Clone, Debug)]
+pub struct CyclicGame {
+    their_letters: Vec<char>,
+    our_letters: Vec<char>,
+}
+
+impl CyclicGame {
+    // A game with a shape for each letter, in cycle order, panicking if there isn't an odd number
+    // of shapes (at least three) or the letters are ambiguous.
+    pub fn new(their_letters: &str, our_letters: &str) -> Self {
+        let their_letters: Vec<char> = their_letters.chars().collect();
+        let our_letters: Vec<char> = our_letters.chars().collect();
+
+        let size = their_letters.len();
+        assert!(
+            size % 2 == 1 && size >= 3,
+            "A cyclic game needs an odd number of shapes, at least three, not {}",
+            size
+        );
+        assert_eq!(
+            our_letters.len(),
+            size,
+            "Both columns need a letter for every shape"
+        );
+        for letters in [&their_letters, &our_letters] {
+            let unique: HashSet<&char> = letters.iter().collect();
+            assert_eq!(
+                unique.len(),
+                size,
+                "Letters must be distinct: {:?}",
+                letters
+            );
+        }
+
+        return CyclicGame {
+            their_letters,
+            our_letters,
+        };
+    }
+
+    // Rock, paper, scissors, as `A`-`C` and `X`-`Z`.
+    pub fn rock_paper_scissors() -> Self {
+        return CyclicGame::new("ABC", "XYZ");
+    }
+
+    // Rock, Spock, paper, lizard, scissors, as `A`-`E` and `V`-`Z`.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        return CyclicGame::new("ABCDE", "VWXYZ");
+    }
+
+    pub fn size(&self) -> usize {
+        return self.their_letters.len();
+    }
+
+    // The letters of the second column, in order.
+    pub fn our_letters(&self) -> &[char] {
+        return &self.our_letters;
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        return (0..self.size()).map(Shape);
+    }
+
+    // How far round the cycle `us` is from `them`.
+    fn distance(&self, them: Shape, us: Shape) -> usize {
+        return (us.0 + self.size() - them.0) % self.size();
+    }
+
+    pub fn outcome(&self, them: Shape, us: Shape) -> Result {
+        let distance = self.distance(them, us);
+        return if distance == 0 {
+            Result::Draw
+        } else if distance <= self.size() / 2 {
+            Result::Win
+        } else {
+            Result::Lose
+        };
+    }
+
+    pub fn score_turn(&self, them: Shape, us: Shape) -> u32 {
+        return us.score() + self.outcome(them, us) as u32;
+    }
+
+    // The shape nearest to theirs in the cycle that gets the result.
+    pub fn select_shape(&self, them: Shape, result: Result) -> Shape {
+        return match result {
+            Result::Draw => them,
+            Result::Win => Shape((them.0 + 1) % self.size()),
+            Result::Lose => Shape((them.0 + self.size() - 1) % self.size()),
+        };
+    }
+
+    // Read a second column letter as a response to their shape. The letters in order ask for the
+    // shapes from half the cycle before theirs to half the cycle after it, so the middle letter
+    // asks for a draw - with three shapes, that's lose, draw, win.
+    pub fn respond(&self, them: Shape, code: usize) -> Shape {
+        let half = self.size() / 2;
+        return Shape((them.0 + self.size() + code - half) % self.size());
+    }
+
+    // List the letters of a column for error messages, like "`A`, `B` or `C`".
+    fn describe(letters: &[char]) -> String {
+        let quoted: Vec<String> = letters.iter().map(|c| format!("`{}`", c)).collect();
+        return match quoted.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => quoted.concat(),
+        };
+    }
+
+    pub fn parse_turn(
+        &self,
+        line_number: usize,
+        line: &str,
+    ) -> std::result::Result<Turn, ParseError> {
+        let chars: Vec<char> = line.chars().collect();
+
+        let them = chars
+            .first()
+            .and_then(|c| self.their_letters.iter().position(|l| l == c))
+            .ok_or_else(|| {
+                let expected = CyclicGame::describe(&self.their_letters);
+                ParseError::in_line(line_number, line, 0, expected)
+            })?;
+        if chars.get(1) != Some(&' ') {
+            let offset = line.char_indices().nth(1).map_or(line.len(), |(i, _)| i);
+            return Err(ParseError::in_line(line_number, line, offset, "a space"));
+        }
+        let code = chars
+            .get(2)
+            .and_then(|c| self.our_letters.iter().position(|l| l == c))
+            .ok_or_else(|| {
+                let offset = line.char_indices().nth(2).map_or(line.len(), |(i, _)| i);
+                let expected = CyclicGame::describe(&self.our_letters);
+                ParseError::in_line(line_number, line, offset, expected)
+            })?;
+        if chars.len() > 3 {
+            let offset = line.char_indices().nth(3).unwrap().0;
+            return Err(ParseError::in_line(
+                line_number,
+                line,
+                offset,
+                "end of line",
+            ));
+        }
+
+        return Ok(Turn {
+            them: Shape(them),
+            code,
+        });
+    }
+
+    pub fn parse_lines(&self, lines: &Vec<String>) -> std::result::Result<Vec<Turn>, ParseError> {
+        return lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| self.parse_turn(index + 1, line))
+            .collect();
+    }
+
+    // The total score when the second column is the shape to play.
+    pub fn score_as_shapes(&self, turns: &[Turn]) -> u32 {
+        return turns
+            .iter()
+            .map(|turn| self.score_turn(turn.them, Shape(turn.code)))
+            .sum();
+    }
+
+    // The total score when the second column is how to respond to their shape.
+    pub fn score_as_responses(&self, turns: &[Turn]) -> u32 {
+        return turns
+            .iter()
+            .map(|turn| self.score_turn(turn.them, self.respond(turn.them, turn.code)))
+            .sum();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn rpsls_test() {
+        let game = CyclicGame::rock_paper_scissors_lizard_spock();
+        let (rock, spock, paper, lizard, scissors) =
+            (Shape(0), Shape(1), Shape(2), Shape(3), Shape(4));
+
+        for (winner, losers) in [
+            (rock, [scissors, lizard]),
+            (spock, [scissors, rock]),
+            (paper, [rock, spock]),
+            (lizard, [spock, paper]),
+            (scissors, [paper, lizard]),
+        ] {
+            for loser in losers {
+                assert_eq!(game.outcome(loser, winner), Result::Win);
+                assert_eq!(game.outcome(winner, loser), Result::Lose);
+            }
+        }
+
+        let turns = game
+            .parse_lines(&vec![String::from("A W"), String::from("E Z")])
+            .unwrap();
+        assert_eq!(game.score_as_shapes(&turns), (2 + 6) + (5 + 3));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let game = CyclicGame::new("pqrst", "12345");
+        let err = game.parse_turn(4, "p 6").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 3));
+        assert_eq!(err.expected, "`1`, `2`, `3`, `4` or `5`");
+    }
+
+    #[test]
+    #[should_panic(expected = "odd number of shapes")]
+    fn even_size_test() {
+        CyclicGame::new("ABCD", "WXYZ");
+    }
+
+    fn game() -> impl Strategy<Value = CyclicGame> {
+        return (1..6usize).prop_map(|half| {
+            let size = 2 * half + 1;
+            let their_letters: String = ('a'..='z').take(size).collect();
+            let our_letters: String = ('A'..='Z').take(size).collect();
+            return CyclicGame::new(&their_letters, &our_letters);
+        });
+    }
+
+    proptest! {
+        #[test]
+        fn every_shape_beats_half(game in game()) {
+            let half = game.size() / 2;
+            for them in game.shapes() {
+                let results: Vec<Result> = game.shapes().map(|us| game.outcome(them, us)).collect();
+                prop_assert_eq!(results.iter().filter(|&&r| r == Result::Win).count(), half);
+                prop_assert_eq!(results.iter().filter(|&&r| r == Result::Lose).count(), half);
+                for us in game.shapes() {
+                    prop_assert_eq!(game.outcome(them, us) as u32 + game.outcome(us, them) as u32, 6);
+                }
+            }
+        }
+
+        #[test]
+        fn select_shape_gets_result(game in game(), them in 0..9usize) {
+            let them = Shape(them % game.size());
+            for result in [Result::Win, Result::Draw, Result::Lose] {
+                prop_assert_eq!(game.outcome(them, game.select_shape(them, result)), result);
+            }
+
+            // The middle response draws, and responses either side of it win and lose.
+            let half = game.size() / 2;
+            for code in 0..game.size() {
+                let expected = match code.cmp(&half) {
+                    std::cmp::Ordering::Less => Result::Lose,
+                    std::cmp::Ordering::Equal => Result::Draw,
+                    std::cmp::Ordering::Greater => Result::Win,
+                };
+                prop_assert_eq!(game.outcome(them, game.respond(them, code)), expected);
+            }
+        }
+    }
+}
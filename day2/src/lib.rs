@@ -0,0 +1,109 @@
+pub mod decode;
+pub mod game;
+pub mod tournament;
+
+use crate::game::{CyclicGame, Turn};
+use aoc_common::{parse_non_empty_lines, ParseError, Solution, SolveError};
+
+pub fn parse_lines(lines: &Vec<String>) -> Result<Vec<Turn>, ParseError> {
+    return CyclicGame::rock_paper_scissors().parse_lines(lines);
+}
+
+pub fn part1(turns: &Vec<Turn>) -> u32 {
+    return CyclicGame::rock_paper_scissors().score_as_shapes(turns);
+}
+
+pub fn part2(turns: &Vec<Turn>) -> u32 {
+    return CyclicGame::rock_paper_scissors().score_as_responses(turns);
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Turn>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Independent scoring from the shape indices, where each shape beats the one before it.
+    fn expected_score(them: u32, us: u32) -> u32 {
+        let outcome = match (us + 3 - them) % 3 {
+            0 => 3,
+            1 => 6,
+            _ => 0,
+        };
+        return us + 1 + outcome;
+    }
+
+    fn turns() -> impl Strategy<Value = Vec<(u32, u32)>> {
+        return prop::collection::vec((0..3u32, 0..3u32), 1..100);
+    }
+
+    fn render(turns: &[(u32, u32)]) -> Vec<String> {
+        return turns
+            .iter()
+            .map(|&(them, us)| {
+                let them = char::from(b'A' + them as u8);
+                let us = char::from(b'X' + us as u8);
+                return format!("{} {}", them, us);
+            })
+            .collect();
+    }
+
+    #[test]
+    fn example_test() {
+        let lines = vec![
+            String::from("A Y"),
+            String::from("B X"),
+            String::from("C Z"),
+        ];
+        let turns = parse_lines(&lines).unwrap();
+        assert_eq!(part1(&turns), 15);
+        assert_eq!(part2(&turns), 12);
+    }
+
+    proptest! {
+        #[test]
+        fn part1_matches_scoring(turns in turns()) {
+            let parsed = parse_lines(&render(&turns)).unwrap();
+            let expected: u32 = turns.iter().map(|&(them, us)| expected_score(them, us)).sum();
+            prop_assert_eq!(part1(&parsed), expected);
+        }
+
+        #[test]
+        fn part2_plays_for_outcome(turns in turns()) {
+            let parsed = parse_lines(&render(&turns)).unwrap();
+            // X, Y and Z ask to lose, draw or win, which is playing the shape before, the same
+            // shape, or the shape after theirs.
+            let expected: u32 = turns
+                .iter()
+                .map(|&(them, outcome)| expected_score(them, (them + outcome + 2) % 3))
+                .sum();
+            prop_assert_eq!(part2(&parsed), expected);
+        }
+
+        #[test]
+        fn parse_rejects_other_letters(them in "[A-Z]", us in "[A-Z]") {
+            let line = format!("{} {}", them, us);
+            let valid = "ABC".contains(&them) && "XYZ".contains(&us);
+            prop_assert_eq!(parse_lines(&vec![line]).is_ok(), valid);
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use crate::game::{CyclicGame, Result, Shape, Turn};
+use std::fmt;
+
+// A round that has been played, from one player's side.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Round {
+    pub ours: Shape,
+    pub theirs: Shape,
+}
+
+// A way of choosing shapes over a game of several rounds.
+pub trait Player {
+    fn name(&self) -> String;
+
+    // The shape to play next, given every round played so far in this game.
+    fn play(&self, game: &CyclicGame, history: &[Round]) -> Shape;
+}
+
+// Always plays the first shape, rock.
+pub struct AlwaysRock;
+
+impl Player for AlwaysRock {
+    fn name(&self) -> String {
+        return String::from("Always rock");
+    }
+
+    fn play(&self, _game: &CyclicGame, _history: &[Round]) -> Shape {
+        return Shape(0);
+    }
+}
+
+// Plays whatever beats the shape they've played most often so far, the earliest in the cycle
+// if there's a tie.
+pub struct FrequencyCounter;
+
+impl Player for FrequencyCounter {
+    fn name(&self) -> String {
+        return String::from("Frequency counter");
+    }
+
+    fn play(&self, game: &CyclicGame, history: &[Round]) -> Shape {
+        let mut counts = vec![0; game.size()];
+        for round in history {
+            counts[round.theirs.0] += 1;
+        }
+
+        let mut favourite = 0;
+        for (shape, &count) in counts.iter().enumerate() {
+            if count > counts[favourite] {
+                favourite = shape;
+            }
+        }
+        return game.select_shape(Shape(favourite), Result::Win);
+    }
+}
+
+// Starts with rock, and keeps playing the same shape while it wins. After a draw or a loss,
+// switches to whatever would have beaten their last shape.
+pub struct WinStayLoseShift;
+
+impl Player for WinStayLoseShift {
+    fn name(&self) -> String {
+        return String::from("Win-stay, lose-shift");
+    }
+
+    fn play(&self, game: &CyclicGame, history: &[Round]) -> Shape {
+        return match history.last() {
+            None => Shape(0),
+            Some(last) if game.outcome(last.theirs, last.ours) == Result::Win => last.ours,
+            Some(last) => game.select_shape(last.theirs, Result::Win),
+        };
+    }
+}
+
+// Plays the shapes a strategy guide asks for in the puzzle's second part, in order, starting
+// again from the top when it runs out.
+pub struct ReplayGuide {
+    shapes: Vec<Shape>,
+}
+
+impl ReplayGuide {
+    pub fn new(game: &CyclicGame, turns: &[Turn]) -> Self {
+        let shapes = turns
+            .iter()
+            .map(|turn| game.respond(turn.them, turn.code))
+            .collect();
+        return ReplayGuide { shapes };
+    }
+}
+
+impl Player for ReplayGuide {
+    fn name(&self) -> String {
+        return String::from("Replay the guide");
+    }
+
+    fn play(&self, _game: &CyclicGame, history: &[Round]) -> Shape {
+        if self.shapes.is_empty() {
+            return Shape(0);
+        }
+        return self.shapes[history.len() % self.shapes.len()];
+    }
+}
+
+// Play a game of `rounds` rounds between two players, returning each player's total score.
+pub fn play_match(
+    game: &CyclicGame,
+    first: &dyn Player,
+    second: &dyn Player,
+    rounds: usize,
+) -> (u32, u32) {
+    let mut first_history: Vec<Round> = Vec::with_capacity(rounds);
+    let mut second_history: Vec<Round> = Vec::with_capacity(rounds);
+    let mut scores = (0, 0);
+
+    for _ in 0..rounds {
+        let first_shape = first.play(game, &first_history);
+        let second_shape = second.play(game, &second_history);
+        scores.0 += game.score_turn(second_shape, first_shape);
+        scores.1 += game.score_turn(first_shape, second_shape);
+
+        first_history.push(Round {
+            ours: first_shape,
+            theirs: second_shape,
+        });
+        second_history.push(Round {
+            ours: second_shape,
+            theirs: first_shape,
+        });
+    }
+
+    return scores;
+}
+
+// A player's record over a tournament. Matches are won on total score, earning three points for
+// a win and one for a draw.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub score: u32,
+}
+
+impl Standing {
+    pub fn played(&self) -> u32 {
+        return self.won + self.drawn + self.lost;
+    }
+
+    pub fn points(&self) -> u32 {
+        return 3 * self.won + self.drawn;
+    }
+}
+
+// The standings after a tournament, top of the table first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct League {
+    pub standings: Vec<Standing>,
+}
+
+// Play every player against every other player once, over `rounds` rounds each game. Players
+// level on points are separated by total score, and then keep the order they were given in.
+pub fn tournament(game: &CyclicGame, players: &[Box<dyn Player>], rounds: usize) -> League {
+    let mut standings: Vec<Standing> = players
+        .iter()
+        .map(|player| Standing {
+            name: player.name(),
+            ..Standing::default()
+        })
+        .collect();
+
+    for first in 0..players.len() {
+        for second in first + 1..players.len() {
+            let (first_score, second_score) =
+                play_match(game, &*players[first], &*players[second], rounds);
+            standings[first].score += first_score;
+            standings[second].score += second_score;
+
+            if first_score > second_score {
+                standings[first].won += 1;
+                standings[second].lost += 1;
+            } else if first_score < second_score {
+                standings[first].lost += 1;
+                standings[second].won += 1;
+            } else {
+                standings[first].drawn += 1;
+                standings[second].drawn += 1;
+            }
+        }
+    }
+
+    standings.sort_by_key(|standing| std::cmp::Reverse((standing.points(), standing.score)));
+    return League { standings };
+}
+
+impl fmt::Display for League {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .standings
+            .iter()
+            .map(|standing| standing.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("Player".len());
+
+        writeln!(
+            f,
+            "{:<width$}  {:>3} {:>3} {:>3} {:>3} {:>4} {:>8}",
+            "Player", "P", "W", "D", "L", "Pts", "Score"
+        )?;
+        for standing in &self.standings {
+            writeln!(
+                f,
+                "{:<width$}  {:>3} {:>3} {:>3} {:>3} {:>4} {:>8}",
+                standing.name,
+                standing.played(),
+                standing.won,
+                standing.drawn,
+                standing.lost,
+                standing.points(),
+                standing.score
+            )?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn frequency_counter_test() {
+        let game = CyclicGame::rock_paper_scissors();
+
+        // With nothing to go on it expects rock, so it wins every round with paper.
+        let scores = play_match(&game, &FrequencyCounter, &AlwaysRock, 3);
+        assert_eq!(scores, (3 * (2 + 6), 3));
+    }
+
+    #[test]
+    fn win_stay_lose_shift_test() {
+        let game = CyclicGame::rock_paper_scissors();
+
+        // Draws the first round with rock, then shifts to paper and stays there.
+        let scores = play_match(&game, &WinStayLoseShift, &AlwaysRock, 4);
+        assert_eq!(scores, ((1 + 3) + 3 * (2 + 6), (1 + 3) + 3));
+    }
+
+    #[test]
+    fn league_test() {
+        let game = CyclicGame::rock_paper_scissors();
+        let turns = crate::parse_lines(&vec![String::from("A Y"), String::from("B X")]).unwrap();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(AlwaysRock),
+            Box::new(FrequencyCounter),
+            Box::new(WinStayLoseShift),
+            Box::new(ReplayGuide::new(&game, &turns)),
+        ];
+
+        let league = tournament(&game, &players, 10);
+        assert_eq!(league.standings.len(), 4);
+        assert!(league
+            .standings
+            .iter()
+            .all(|standing| standing.played() == 3));
+
+        // The guide only ever plays rock too, so that's the only game it doesn't lose. The two of
+        // them tie, and keep the order they were given in at the bottom of the table.
+        let names: Vec<&str> = league.standings[2..]
+            .iter()
+            .map(|standing| standing.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Always rock", "Replay the guide"]);
+        let always_rock = &league.standings[2];
+        assert_eq!(
+            (always_rock.won, always_rock.drawn, always_rock.lost),
+            (0, 1, 2)
+        );
+
+        let table = league.to_string();
+        assert!(table.starts_with("Player"));
+        assert_eq!(table.lines().count(), 5);
+    }
+
+    proptest! {
+        #[test]
+        fn league_adds_up(codes in prop::collection::vec((0..3usize, 0..3usize), 1..20), rounds in 0..30usize) {
+            let game = CyclicGame::rock_paper_scissors();
+            let turns: Vec<Turn> = codes
+                .iter()
+                .map(|&(them, code)| Turn { them: Shape(them), code })
+                .collect();
+            let players: Vec<Box<dyn Player>> = vec![
+                Box::new(AlwaysRock),
+                Box::new(FrequencyCounter),
+                Box::new(WinStayLoseShift),
+                Box::new(ReplayGuide::new(&game, &turns)),
+            ];
+            let league = tournament(&game, &players, rounds);
+
+            let won: u32 = league.standings.iter().map(|standing| standing.won).sum();
+            let lost: u32 = league.standings.iter().map(|standing| standing.lost).sum();
+            prop_assert_eq!(won, lost);
+
+            // Every round hands out 6 points for the result between the two players, plus
+            // between 2 and 6 for the shapes they played.
+            let matches = (players.len() * (players.len() - 1) / 2 * rounds) as u32;
+            let total: u32 = league.standings.iter().map(|standing| standing.score).sum();
+            prop_assert!(8 * matches <= total && total <= 12 * matches);
+
+            for pair in league.standings.windows(2) {
+                prop_assert!((pair[0].points(), pair[0].score) >= (pair[1].points(), pair[1].score));
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+use day3::Day3;
+
+aoc_common::bench_main!(Day3, ["input"]);
@@ -0,0 +1,183 @@
+use crate::calc_priority;
+use std::ops::{BitAnd, BitOr};
+
+// A set of items, with bit `p` set for the item with priority `p`. Priorities go from 1 to 52, so
+// every set fits in one word, and intersecting sets is a single AND.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Items(pub u64);
+
+impl Items {
+    // Every item in `items`, ignoring anything that isn't an item letter.
+    pub fn from_letters(items: &[char]) -> Self {
+        let mut bits = 0;
+        for &c in items {
+            let priority = calc_priority(c);
+            if priority > 0 {
+                bits |= 1 << priority;
+            }
+        }
+        return Items(bits);
+    }
+
+    // Every possible item.
+    pub fn all() -> Self {
+        return Items(((1 << 53) - 1) & !1);
+    }
+
+    pub fn len(self) -> usize {
+        return self.0.count_ones() as usize;
+    }
+
+    pub fn is_empty(self) -> bool {
+        return self.0 == 0;
+    }
+
+    pub fn contains(self, c: char) -> bool {
+        return !(self & Items::from_letters(&[c])).is_empty();
+    }
+
+    // The priorities of the items in the set, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        return (1..=52).filter(move |p| self.0 & (1 << p) != 0);
+    }
+
+    // The item letters in the set, in priority order.
+    pub fn letters(self) -> impl Iterator<Item = char> {
+        return self.priorities().map(item_letter);
+    }
+
+    pub fn priority_sum(self) -> u32 {
+        return self.priorities().sum();
+    }
+}
+
+impl BitAnd for Items {
+    type Output = Items;
+
+    fn bitand(self, other: Items) -> Items {
+        return Items(self.0 & other.0);
+    }
+}
+
+impl BitOr for Items {
+    type Output = Items;
+
+    fn bitor(self, other: Items) -> Items {
+        return Items(self.0 | other.0);
+    }
+}
+
+// The item letter with a given priority, from 1 to 52.
+pub fn item_letter(priority: u32) -> char {
+    return match priority {
+        1..=26 => char::from(b'a' + (priority - 1) as u8),
+        27..=52 => char::from(b'A' + (priority - 27) as u8),
+        _ => panic!("No item has priority {}", priority),
+    };
+}
+
+// A rucksack's items, split into its two compartments. With an odd number of items, the extra
+// one goes in the second compartment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rucksack {
+    pub first: Items,
+    pub second: Items,
+}
+
+impl Rucksack {
+    pub fn new(items: &[char]) -> Self {
+        let (first, second) = items.split_at(items.len() / 2);
+        return Rucksack {
+            first: Items::from_letters(first),
+            second: Items::from_letters(second),
+        };
+    }
+
+    pub fn items(self) -> Items {
+        return self.first | self.second;
+    }
+
+    // The items in both compartments.
+    pub fn shared(self) -> Items {
+        return self.first & self.second;
+    }
+}
+
+// Split the rucksacks into groups of `group_size`, and find every item all the rucksacks in each
+// group have in common. A last group with fewer rucksacks is still checked.
+pub fn common_items(rucksacks: &[Vec<char>], group_size: usize) -> Vec<Items> {
+    assert!(group_size > 0, "A group has to have at least one rucksack");
+    return rucksacks
+        .chunks(group_size)
+        .map(|group| {
+            group
+                .iter()
+                .map(|items| Items::from_letters(items))
+                .fold(Items::all(), |common, items| common & items)
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    #[should_panic(expected = "at least one rucksack")]
+    fn empty_group_test() {
+        common_items(&[vec!['a', 'a']], 0);
+    }
+
+    #[test]
+    fn items_test() {
+        let items = Items::from_letters(&['b', 'a', 'Z', 'a']);
+        assert_eq!(items.len(), 3);
+        assert_eq!(items.letters().collect::<String>(), "abZ");
+        assert_eq!(items.priority_sum(), 1 + 2 + 52);
+        assert!(items.contains('Z') && !items.contains('z'));
+        assert_eq!(Items::all().len(), 52);
+    }
+
+    #[test]
+    fn common_items_test() {
+        let rucksacks: Vec<Vec<char>> = ["abcd", "bcde", "cdef", "xyz", "zyw"]
+            .iter()
+            .map(|line| line.chars().collect())
+            .collect();
+
+        let pairs: Vec<String> = common_items(&rucksacks, 2)
+            .iter()
+            .map(|items| items.letters().collect())
+            .collect();
+        assert_eq!(pairs, vec!["bcd", "", "wyz"]);
+
+        let triples: Vec<String> = common_items(&rucksacks, 3)
+            .iter()
+            .map(|items| items.letters().collect())
+            .collect();
+        assert_eq!(triples, vec!["cd", "yz"]);
+    }
+
+    proptest! {
+        #[test]
+        fn common_items_match_sets(
+            rucksacks in prop::collection::vec(prop::collection::vec(prop::char::ranges(vec!['a'..='z', 'A'..='Z'].into()), 0..30), 1..20),
+            group_size in 1..6usize,
+        ) {
+            let common = common_items(&rucksacks, group_size);
+            prop_assert_eq!(common.len(), rucksacks.len().div_ceil(group_size));
+
+            for (group, items) in rucksacks.chunks(group_size).zip(common) {
+                let mut expected: HashSet<char> = group[0].iter().copied().collect();
+                for rucksack in &group[1..] {
+                    let other: HashSet<char> = rucksack.iter().copied().collect();
+                    expected = expected.intersection(&other).copied().collect();
+                }
+                let found: HashSet<char> = items.letters().collect();
+                prop_assert_eq!(found, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+pub mod items;
+pub mod validate;
+
+use crate::items::{common_items, Rucksack};
+use aoc_common::{parse_non_empty_lines, ParseError, Solution, SolveError};
+
+pub fn calc_priority(c: char) -> u32 {
+    return match c {
+        'a'..='z' => (c as u32) - ('a' as u32) + 1,
+        'A'..='Z' => (c as u32) - ('A' as u32) + 27,
+        _ => 0,
+    };
+}
+
+pub fn parse_lines(lines: &Vec<String>) -> Result<Vec<Vec<char>>, ParseError> {
+    return lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            if let Some(offset) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::in_line(
+                    index + 1,
+                    line,
+                    offset,
+                    "an item letter",
+                ));
+            }
+            return Ok(line.chars().collect());
+        })
+        .collect();
+}
+
+// Sum the priorities of the items in both compartments of each rucksack.
+pub fn part1(lines: &Vec<Vec<char>>) -> u32 {
+    return lines
+        .iter()
+        .map(|line| Rucksack::new(line).shared().priority_sum())
+        .sum();
+}
+
+// Sum the priorities of the badges each group of three elves have in common.
+pub fn part2(lines: &Vec<Vec<char>>) -> u32 {
+    return common_items(lines, 3)
+        .iter()
+        .map(|badges| badges.priority_sum())
+        .sum();
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn item_letters() -> Vec<char> {
+        return ('a'..='z').chain('A'..='Z').collect();
+    }
+
+    // A rucksack whose compartments share exactly one item, along with that item.
+    fn rucksack() -> impl Strategy<Value = (char, Vec<char>)> {
+        return (Just(item_letters()).prop_shuffle(), 1..12usize)
+            .prop_flat_map(|(letters, size)| {
+                let first =
+                    prop::collection::vec(prop::sample::select(letters[1..26].to_vec()), size - 1);
+                let second =
+                    prop::collection::vec(prop::sample::select(letters[26..].to_vec()), size - 1);
+                return (Just(letters[0]), first, second, 0..size, 0..size);
+            })
+            .prop_map(|(shared, mut first, mut second, i, j)| {
+                first.insert(i, shared);
+                second.insert(j, shared);
+                first.extend(second);
+                return (shared, first);
+            });
+    }
+
+    // Three rucksacks with exactly one item in common, along with that badge.
+    fn group() -> impl Strategy<Value = (char, Vec<Vec<char>>)> {
+        return Just(item_letters())
+            .prop_shuffle()
+            .prop_flat_map(|letters| {
+                let elf = |pool: &[char]| {
+                    let items = prop::collection::vec(prop::sample::select(pool.to_vec()), 0..20);
+                    return (items, any::<prop::sample::Index>());
+                };
+                return (
+                    Just(letters[0]),
+                    [
+                        elf(&letters[1..18]),
+                        elf(&letters[18..35]),
+                        elf(&letters[35..]),
+                    ],
+                );
+            })
+            .prop_map(|(badge, elves)| {
+                let rucksacks = elves
+                    .into_iter()
+                    .map(|(mut items, at)| {
+                        items.insert(at.index(items.len() + 1), badge);
+                        return items;
+                    })
+                    .collect();
+                return (badge, rucksacks);
+            });
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = vec![
+            String::from("vJrwpWtwJgWrhcsFMMfFFhFp").chars().collect(),
+            String::from("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL")
+                .chars()
+                .collect(),
+            String::from("PmmdzqPrVvPwwTWBwg").chars().collect(),
+            String::from("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn")
+                .chars()
+                .collect(),
+            String::from("ttgJtRGJQctTZtZT").chars().collect(),
+            String::from("CrZsJsPPZsGzwwsLwLmpwMDw").chars().collect(),
+        ];
+        let result = part1(&lines);
+        assert_eq!(result, 157);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = vec![
+            String::from("vJrwpWtwJgWrhcsFMMfFFhFp").chars().collect(),
+            String::from("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL")
+                .chars()
+                .collect(),
+            String::from("PmmdzqPrVvPwwTWBwg").chars().collect(),
+            String::from("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn")
+                .chars()
+                .collect(),
+            String::from("ttgJtRGJQctTZtZT").chars().collect(),
+            String::from("CrZsJsPPZsGzwwsLwLmpwMDw").chars().collect(),
+        ];
+        let result = part2(&lines);
+        assert_eq!(result, 70);
+    }
+
+    proptest! {
+        #[test]
+        fn part1_finds_shared_item(rucksacks in prop::collection::vec(rucksack(), 1..20)) {
+            let lines: Vec<String> = rucksacks.iter().map(|(_, items)| items.iter().collect()).collect();
+            let parsed = parse_lines(&lines).unwrap();
+
+            let expected: u32 = rucksacks.iter().map(|(shared, _)| calc_priority(*shared)).sum();
+            prop_assert_eq!(part1(&parsed), expected);
+        }
+
+        #[test]
+        fn part2_finds_badges(groups in prop::collection::vec(group(), 1..10)) {
+            let rucksacks: Vec<Vec<char>> = groups.iter().flat_map(|(_, elves)| elves.clone()).collect();
+
+            let expected: u32 = groups.iter().map(|(badge, _)| calc_priority(*badge)).sum();
+            prop_assert_eq!(part2(&rucksacks), expected);
+        }
+
+        #[test]
+        fn priorities_are_distinct(a in "[a-zA-Z]", b in "[a-zA-Z]") {
+            let (a, b) = (a.chars().next().unwrap(), b.chars().next().unwrap());
+            prop_assert!((1..=52).contains(&calc_priority(a)));
+            prop_assert_eq!(calc_priority(a) == calc_priority(b), a == b);
+        }
+    }
+}
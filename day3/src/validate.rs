@@ -0,0 +1,391 @@
+use crate::calc_priority;
+use crate::items::{common_items, item_letter, Items, Rucksack};
+use std::fmt;
+
+// Something wrong with the rucksacks, where rucksacks and groups are numbered from 0 in the order
+// they're listed. A valid rucksack has an even number of items, and exactly one kind of item in
+// both compartments, and each group of elves should have exactly one item in common, their badge.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Problem {
+    // Splitting the items in half leaves one over.
+    OddLength { rucksack: usize, len: usize },
+
+    // There's no item in both compartments, or there are several.
+    SharedItems { rucksack: usize, shared: Items },
+
+    // There's no item the group has in common, or there are several.
+    Badges { group: usize, badges: Items },
+}
+
+// List items like "`a`, `b` and `C`", or "none".
+fn describe_items(items: Items) -> String {
+    let quoted: Vec<String> = items.letters().map(|c| format!("`{}`", c)).collect();
+    return match quoted.split_last() {
+        None => String::from("none"),
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} and {}", rest.join(", "), last),
+    };
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Problem::OddLength { rucksack, len } => write!(
+                f,
+                "rucksack {} has an odd number of items ({}), so its compartments don't match",
+                rucksack + 1,
+                len
+            ),
+            Problem::SharedItems { rucksack, shared } => write!(
+                f,
+                "rucksack {} should have one item in both compartments, but has {}",
+                rucksack + 1,
+                describe_items(*shared)
+            ),
+            Problem::Badges { group, badges } => write!(
+                f,
+                "group {} should have one badge in common, but has {}",
+                group + 1,
+                describe_items(*badges)
+            ),
+        };
+    }
+}
+
+// Check every rucksack, and every group of `group_size` rucksacks.
+pub fn validate(rucksacks: &[Vec<char>], group_size: usize) -> Vec<Problem> {
+    assert!(group_size > 0, "A group has to have at least one rucksack");
+    let mut problems = Vec::new();
+
+    for (index, items) in rucksacks.iter().enumerate() {
+        if items.len() % 2 == 1 {
+            problems.push(Problem::OddLength {
+                rucksack: index,
+                len: items.len(),
+            });
+            continue;
+        }
+
+        let shared = Rucksack::new(items).shared();
+        if shared.len() != 1 {
+            problems.push(Problem::SharedItems {
+                rucksack: index,
+                shared,
+            });
+        }
+    }
+
+    for (index, badges) in common_items(rucksacks, group_size).into_iter().enumerate() {
+        if badges.len() != 1 {
+            problems.push(Problem::Badges {
+                group: index,
+                badges,
+            });
+        }
+    }
+
+    return problems;
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Compartment {
+    First,
+    Second,
+}
+
+// Move `count` of an item out of one compartment of a rucksack into the other.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Move {
+    pub rucksack: usize,
+    pub item: char,
+    pub count: usize,
+    pub from: Compartment,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (from, to) = match self.from {
+            Compartment::First => ("first", "second"),
+            Compartment::Second => ("second", "first"),
+        };
+        return write!(
+            f,
+            "rucksack {}: move {} `{}` from the {} compartment to the {}",
+            self.rucksack + 1,
+            self.count,
+            self.item,
+            from,
+            to
+        );
+    }
+}
+
+// The moves that make every rucksack valid, moving as few items as possible, along with the
+// rucksacks that can't be made valid just by moving items between their compartments.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Plan {
+    pub moves: Vec<Move>,
+    pub impossible: Vec<usize>,
+}
+
+impl Plan {
+    // The number of items moved in total.
+    pub fn cost(&self) -> usize {
+        return self.moves.iter().map(|m| m.count).sum();
+    }
+}
+
+pub fn plan(rucksacks: &[Vec<char>]) -> Plan {
+    let mut plan = Plan::default();
+    for (index, items) in rucksacks.iter().enumerate() {
+        match plan_rucksack(items) {
+            Some(moves) => plan
+                .moves
+                .extend(moves.into_iter().map(|(item, count, from)| Move {
+                    rucksack: index,
+                    item,
+                    count,
+                    from,
+                })),
+            None => plan.impossible.push(index),
+        }
+    }
+    return plan;
+}
+
+// How many of each kind of item are in each compartment, indexed by priority.
+fn count_items(items: &[char]) -> ([usize; 53], [usize; 53]) {
+    let (first, second) = items.split_at(items.len() / 2);
+    let mut counts = ([0; 53], [0; 53]);
+    for &c in first {
+        counts.0[calc_priority(c) as usize] += 1;
+    }
+    for &c in second {
+        counts.1[calc_priority(c) as usize] += 1;
+    }
+    return counts;
+}
+
+// Find the fewest items to move between the compartments of a rucksack to make it valid, as
+// `(item, count, from)`, or `None` if it can't be done.
+//
+// The compartments have to stay the same size, so this is a knapsack: one kind of item is split
+// across both compartments, and every other kind goes wholly into one of them, costing however
+// many of it are in the other one. Try each kind as the shared one, filling the first compartment
+// from the rest as cheaply as possible.
+pub fn plan_rucksack(items: &[char]) -> Option<Vec<(char, usize, Compartment)>> {
+    if items.len() % 2 == 1 {
+        return None;
+    }
+    let half = items.len() / 2;
+    let (first, second) = count_items(items);
+    let kinds: Vec<usize> = (1..=52).filter(|&p| first[p] + second[p] > 0).collect();
+
+    // The cheapest plan so far, as the cost and how many of each kind end up in the first
+    // compartment.
+    let mut best: Option<(usize, Vec<usize>)> = None;
+
+    for &shared in &kinds {
+        let total = first[shared] + second[shared];
+        if total < 2 {
+            continue;
+        }
+        let others: Vec<usize> = kinds.iter().copied().filter(|&p| p != shared).collect();
+
+        // costs[i][k] is the cheapest way to put `k` items in the first compartment using only
+        // the first `i` other kinds.
+        let mut costs: Vec<Vec<Option<usize>>> = vec![vec![None; half + 1]; others.len() + 1];
+        costs[0][0] = Some(0);
+        for (i, &p) in others.iter().enumerate() {
+            let count = first[p] + second[p];
+            for k in 0..=half {
+                let into_second = costs[i][k].map(|cost| cost + first[p]);
+                let into_first = k
+                    .checked_sub(count)
+                    .and_then(|rest| costs[i][rest])
+                    .map(|cost| cost + second[p]);
+                costs[i + 1][k] = match (into_first, into_second) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+            }
+        }
+
+        for in_first in 1..total.min(half + 1) {
+            let Some(rest) = costs[others.len()][half - in_first] else {
+                continue;
+            };
+            let cost = rest + in_first.abs_diff(first[shared]);
+            if best
+                .as_ref()
+                .is_some_and(|(best_cost, _)| *best_cost <= cost)
+            {
+                continue;
+            }
+
+            // Work back through the table to see where each other kind went.
+            let mut placed = vec![0; 53];
+            placed[shared] = in_first;
+            let mut k = half - in_first;
+            for (i, &p) in others.iter().enumerate().rev() {
+                let count = first[p] + second[p];
+                let via_first = k
+                    .checked_sub(count)
+                    .and_then(|rest| costs[i][rest])
+                    .map(|cost| cost + second[p]);
+                if via_first.is_some() && via_first == costs[i + 1][k] {
+                    placed[p] = count;
+                    k -= count;
+                }
+            }
+            best = Some((cost, placed));
+        }
+    }
+
+    let (_, placed) = best?;
+    let moves = kinds
+        .iter()
+        .filter_map(|&p| {
+            let item = item_letter(p as u32);
+            return match placed[p].cmp(&first[p]) {
+                std::cmp::Ordering::Less => Some((item, first[p] - placed[p], Compartment::First)),
+                std::cmp::Ordering::Greater => {
+                    Some((item, placed[p] - first[p], Compartment::Second))
+                }
+                std::cmp::Ordering::Equal => None,
+            };
+        })
+        .collect();
+    return Some(moves);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn rucksacks(lines: &[&str]) -> Vec<Vec<char>> {
+        return lines.iter().map(|line| line.chars().collect()).collect();
+    }
+
+    #[test]
+    fn validate_test() {
+        let lines = rucksacks(&["abca", "abcabc", "abcab", "xyzwxa"]);
+        let problems = validate(&lines, 2);
+        assert_eq!(
+            problems,
+            vec![
+                Problem::SharedItems {
+                    rucksack: 1,
+                    shared: Items::from_letters(&['a', 'b', 'c'])
+                },
+                Problem::OddLength {
+                    rucksack: 2,
+                    len: 5
+                },
+                Problem::Badges {
+                    group: 0,
+                    badges: Items::from_letters(&['a', 'b', 'c'])
+                },
+            ]
+        );
+        assert_eq!(
+            problems[0].to_string(),
+            "rucksack 2 should have one item in both compartments, but has `a`, `b` and `c`"
+        );
+        assert!(validate(&rucksacks(&["abcb", "xbyx"]), 2).is_empty());
+    }
+
+    #[test]
+    fn plan_test() {
+        // Keep one `a` on each side, and gather up the `c`s in the first compartment and the `b`s
+        // in the second.
+        let plan = plan(&rucksacks(&["abca", "abcabc", "abab", "abc"]));
+        assert_eq!(plan.cost(), 2);
+        assert_eq!(
+            plan.moves,
+            vec![
+                Move {
+                    rucksack: 1,
+                    item: 'b',
+                    count: 1,
+                    from: Compartment::First
+                },
+                Move {
+                    rucksack: 1,
+                    item: 'c',
+                    count: 1,
+                    from: Compartment::Second
+                },
+            ]
+        );
+        assert_eq!(
+            plan.moves[0].to_string(),
+            "rucksack 2: move 1 `b` from the first compartment to the second"
+        );
+
+        // Two kinds of item in pairs can't be split so that only one is shared, and an odd
+        // number of items can't be split at all.
+        assert_eq!(plan.impossible, vec![2, 3]);
+    }
+
+    // Move the items as planned, a kind at a time.
+    fn apply(items: &[char], moves: &[(char, usize, Compartment)]) -> (Vec<char>, Vec<char>) {
+        let (first, second) = items.split_at(items.len() / 2);
+        let (mut first, mut second) = (first.to_vec(), second.to_vec());
+        for &(item, count, from) in moves {
+            let (from, to) = match from {
+                Compartment::First => (&mut first, &mut second),
+                Compartment::Second => (&mut second, &mut first),
+            };
+            for _ in 0..count {
+                let at = from.iter().position(|&c| c == item).unwrap();
+                to.push(from.remove(at));
+            }
+        }
+        return (first, second);
+    }
+
+    // The fewest items moved over every way of splitting the items in half, or `None`.
+    fn brute_force(items: &[char]) -> Option<usize> {
+        let half = items.len() / 2;
+        let mut best = None;
+        for mask in 0u32..1 << items.len() {
+            if mask.count_ones() as usize != half || items.len() % 2 == 1 {
+                continue;
+            }
+            let first: Vec<char> = (0..items.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| items[i])
+                .collect();
+            let second: Vec<char> = (0..items.len())
+                .filter(|i| mask & (1 << i) == 0)
+                .map(|i| items[i])
+                .collect();
+            if (Items::from_letters(&first) & Items::from_letters(&second)).len() != 1 {
+                continue;
+            }
+            let moved = 2 * (0..half).filter(|i| mask & (1 << i) == 0).count();
+            best = Some(best.map_or(moved, |b: usize| b.min(moved)));
+        }
+        return best;
+    }
+
+    proptest! {
+        #[test]
+        fn plan_is_minimal(items in prop::collection::vec(prop::sample::select(vec!['a', 'b', 'c', 'D']), 0..13)) {
+            let plan = plan_rucksack(&items);
+            prop_assert_eq!(
+                plan.as_ref().map(|moves| moves.iter().map(|m| m.1).sum::<usize>()),
+                brute_force(&items)
+            );
+
+            if let Some(moves) = plan {
+                let (first, second) = apply(&items, &moves);
+                prop_assert_eq!(first.len(), second.len());
+                let rearranged = [first, second].concat();
+                prop_assert_eq!(Rucksack::new(&rearranged).shared().len(), 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+use day4::Day4;
+
+aoc_common::bench_main!(Day4, ["input"]);
@@ -0,0 +1,190 @@
+use aoc_common::Interval;
+
+// The section ranges assigned to each elf in a crew, in the order they're listed.
+pub type Crew = Vec<Interval<u32>>;
+
+// The sections covered by anyone in the crew, as separate ranges in order. Ranges that touch,
+// like `2-4` and `5-6`, are joined.
+pub fn covered(crew: &[Interval<u32>]) -> Vec<Interval<u32>> {
+    let mut sorted = crew.to_vec();
+    sorted.sort_by_key(|range| range.start());
+
+    let mut merged: Vec<Interval<u32>> = Vec::new();
+    for range in sorted {
+        match merged.last_mut() {
+            Some(last) if last.union(&range).is_some() => *last = last.union(&range).unwrap(),
+            _ => merged.push(range),
+        }
+    }
+    return merged;
+}
+
+// The sections within `bound` that nobody in the crew covers.
+pub fn uncovered(crew: &[Interval<u32>], bound: Interval<u32>) -> Vec<Interval<u32>> {
+    let mut gaps = vec![bound];
+    for range in covered(crew) {
+        gaps = gaps.iter().flat_map(|gap| gap.difference(&range)).collect();
+    }
+    return gaps;
+}
+
+// The sections covered by more than one elf in the crew, in order.
+pub fn multiply_covered(crew: &[Interval<u32>]) -> Vec<Interval<u32>> {
+    // Sweep along the sections, keeping count of the elves covering each one. Each range adds an
+    // elf at its start, and takes it away again after its end.
+    let mut events: Vec<(u64, i32)> = Vec::with_capacity(2 * crew.len());
+    for range in crew {
+        events.push((range.start() as u64, 1));
+        events.push((range.end() as u64 + 1, -1));
+    }
+    events.sort();
+
+    let mut overlaps: Vec<Interval<u32>> = Vec::new();
+    let mut count = 0;
+    for (index, &(section, change)) in events.iter().enumerate() {
+        count += change;
+        let next = events.get(index + 1).map(|&(next, _)| next);
+        if count < 2 || next.is_none_or(|next| next == section) {
+            continue;
+        }
+
+        // The count stays the same until the next event.
+        let range = Interval::new(section as u32, (next.unwrap() - 1) as u32);
+        match overlaps.last_mut() {
+            Some(last) if last.union(&range).is_some() => *last = last.union(&range).unwrap(),
+            _ => overlaps.push(range),
+        }
+    }
+    return overlaps;
+}
+
+// The fewest elves that still cover every section the whole crew does, as indices into the crew
+// in order.
+//
+// Within each stretch of covered sections, start with the earliest starting elf that reaches furthest,
+// then repeatedly add whichever elf starting no later than just after the sections covered so far
+// reaches furthest. No cover can reach further with fewer elves, so this is optimal.
+pub fn minimal_cover(crew: &[Interval<u32>]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..crew.len()).collect();
+    order.sort_by_key(|&index| (crew[index].start(), std::cmp::Reverse(crew[index].end())));
+
+    let mut chosen = Vec::new();
+    let mut next = 0;
+    while next < order.len() {
+        // Start a new stretch with the range that starts first.
+        let first = order[next];
+        chosen.push(first);
+        let mut reach = crew[first].end() as u64;
+        next += 1;
+
+        loop {
+            let mut best: Option<usize> = None;
+            while next < order.len() && crew[order[next]].start() as u64 <= reach + 1 {
+                let candidate = order[next];
+                if best.is_none_or(|best| crew[candidate].end() > crew[best].end()) {
+                    best = Some(candidate);
+                }
+                next += 1;
+            }
+
+            match best {
+                Some(best) if crew[best].end() as u64 > reach => {
+                    chosen.push(best);
+                    reach = crew[best].end() as u64;
+                }
+                _ => break,
+            }
+        }
+    }
+
+    chosen.sort();
+    return chosen;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn crew(ranges: &[(u32, u32)]) -> Crew {
+        return ranges
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect();
+    }
+
+    #[test]
+    fn coverage_test() {
+        let crew = crew(&[(2, 4), (6, 8), (3, 6), (12, 12)]);
+        assert_eq!(
+            covered(&crew),
+            vec![Interval::new(2, 8), Interval::new(12, 12)]
+        );
+        assert_eq!(
+            uncovered(&crew, Interval::new(1, 13)),
+            vec![
+                Interval::new(1, 1),
+                Interval::new(9, 11),
+                Interval::new(13, 13)
+            ]
+        );
+        assert_eq!(
+            multiply_covered(&crew),
+            vec![Interval::new(3, 4), Interval::new(6, 6)]
+        );
+
+        // Every elf is needed, as `3-6` joins up the first two.
+        assert_eq!(minimal_cover(&crew), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn minimal_cover_test() {
+        // `1-5` reaches furthest from the start, then `2-10` is the first to reach the end.
+        let crew = crew(&[(1, 3), (1, 5), (4, 9), (6, 10), (2, 10)]);
+        assert_eq!(minimal_cover(&crew), vec![1, 4]);
+        assert_eq!(minimal_cover(&[]), Vec::<usize>::new());
+    }
+
+    fn crews() -> impl Strategy<Value = Crew> {
+        let range = (0..30u32, 0..8u32).prop_map(|(start, len)| Interval::new(start, start + len));
+        return prop::collection::vec(range, 0..8);
+    }
+
+    fn sections(ranges: &[Interval<u32>]) -> BTreeSet<u32> {
+        return ranges.iter().flat_map(|range| range.values()).collect();
+    }
+
+    proptest! {
+        #[test]
+        fn queries_match_sections(crew in crews()) {
+            let all = sections(&crew);
+            prop_assert_eq!(sections(&covered(&crew)), all.clone());
+
+            let bound = Interval::new(5, 25);
+            let expected: BTreeSet<u32> = bound.values().filter(|s| !all.contains(s)).collect();
+            prop_assert_eq!(sections(&uncovered(&crew, bound)), expected);
+
+            let shared: BTreeSet<u32> = all
+                .iter()
+                .copied()
+                .filter(|&s| crew.iter().filter(|range| range.contains(s)).count() > 1)
+                .collect();
+            prop_assert_eq!(sections(&multiply_covered(&crew)), shared);
+
+            // The cover covers everything, and no smaller set of elves does.
+            let cover = minimal_cover(&crew);
+            let chosen: Crew = cover.iter().map(|&index| crew[index]).collect();
+            prop_assert_eq!(sections(&chosen), all.clone());
+            for size in 0..cover.len() {
+                for mask in 0u32..1 << crew.len() {
+                    if mask.count_ones() as usize != size {
+                        continue;
+                    }
+                    let subset: Crew = (0..crew.len()).filter(|i| mask & (1 << i) != 0).map(|i| crew[i]).collect();
+                    prop_assert_ne!(sections(&subset), all.clone());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+pub mod crew;
+
+use crate::crew::Crew;
+use aoc_common::{parse_non_empty_lines, parse_num, Interval, ParseError, Solution, SolveError};
+use regex::{Captures, Regex};
+
+// Build a range from its `start` and `end` capture groups, which can't be the wrong way round.
+fn parse_interval(caps: &Captures, line: usize) -> Result<Interval<u32>, ParseError> {
+    let start = parse_num(caps, "start", line)?;
+    let end = parse_num(caps, "end", line)?;
+    return Interval::try_new(start, end).ok_or_else(|| {
+        let m = caps.name("end").unwrap();
+        let expected = format!("a section number no less than {}", start);
+        ParseError::new(line, m.start() + 1, expected, m.as_str())
+    });
+}
+
+// Parse each line into a crew's section ranges, one or more separated by commas.
+pub fn parse_lines(lines: &Vec<String>) -> Result<Vec<Crew>, ParseError> {
+    let line_re = Regex::new(r"^\d+-\d+(,\d+-\d+)*$").unwrap();
+    let range_re = Regex::new(r"(?P<start>\d+)-(?P<end>\d+)").unwrap();
+    return lines
+        .iter()
+        .enumerate()
+        .map(|(index, l)| {
+            let line = index + 1;
+            if !line_re.is_match(l) {
+                let expected = "comma-separated ranges like `2-4,6-8`";
+                return Err(ParseError::bad_line(line, l, expected));
+            }
+            return range_re
+                .captures_iter(l)
+                .map(|caps| parse_interval(&caps, line))
+                .collect();
+        })
+        .collect();
+}
+
+// Whether any elf's range is entirely inside another elf's range.
+fn any_contained(crew: &Crew) -> bool {
+    return crew.iter().enumerate().any(|(i, r1)| {
+        crew.iter()
+            .enumerate()
+            .any(|(j, r2)| i != j && r1.contains_interval(r2))
+    });
+}
+
+// Whether any two elves' ranges overlap.
+fn any_overlap(crew: &Crew) -> bool {
+    return crew
+        .iter()
+        .enumerate()
+        .any(|(i, r1)| crew[i + 1..].iter().any(|r2| r1.overlaps(r2)));
+}
+
+pub fn part1(crews: &Vec<Crew>) -> usize {
+    return crews.iter().filter(|crew| any_contained(crew)).count();
+}
+
+pub fn part2(crews: &Vec<Crew>) -> usize {
+    return crews.iter().filter(|crew| any_overlap(crew)).count();
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Crew>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    // A line of two section ranges, each written `start-end` with `start <= end`.
+    fn range_pair() -> impl Strategy<Value = String> {
+        let range = (1..100u32, 0..20u32).prop_map(|(start, len)| (start, start + len));
+        return (range.clone(), range)
+            .prop_map(|((s1, e1), (s2, e2))| format!("{}-{},{}-{}", s1, e1, s2, e2));
+    }
+
+    // The sections each elf in a pair is assigned, compared as sets instead of by their bounds.
+    fn section_sets(pair: &Crew) -> (HashSet<u32>, HashSet<u32>) {
+        let sections = |r: &Interval<u32>| (r.start()..=r.end()).collect();
+        return (sections(&pair[0]), sections(&pair[1]));
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = vec![
+            String::from("2-4,6-8"),
+            String::from("2-3,4-5"),
+            String::from("5-7,7-9"),
+            String::from("2-8,3-7"),
+            String::from("6-6,4-6"),
+            String::from("2-6,4-8"),
+        ];
+        let result = part1(&parse_lines(&lines).unwrap());
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = vec![
+            String::from("2-4,6-8"),
+            String::from("2-3,4-5"),
+            String::from("5-7,7-9"),
+            String::from("2-8,3-7"),
+            String::from("6-6,4-6"),
+            String::from("2-6,4-8"),
+        ];
+        let result = part2(&parse_lines(&lines).unwrap());
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let lines = vec![String::from("2-4,6-8"), String::from("2-3;4-5")];
+        let err = parse_lines(&lines).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.found, "2-3;4-5");
+    }
+
+    #[test]
+    fn single_section_test() {
+        let lines = vec![
+            String::from("6-6,4-6"),
+            String::from("6-6,6-6"),
+            String::from("6-6,7-7"),
+            String::from("5-5,6-9"),
+        ];
+        let pairs = parse_lines(&lines).unwrap();
+        assert_eq!(pairs[0][0].len(), 1);
+        assert_eq!(part1(&pairs), 2);
+        assert_eq!(part2(&pairs), 2);
+    }
+
+    #[test]
+    fn backwards_range_test() {
+        let err = parse_lines(&vec![String::from("2-4,8-6")]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        assert_eq!(err.expected, "a section number no less than 8");
+    }
+
+    #[test]
+    fn crew_test() {
+        let lines = vec![
+            String::from("1-3,5-7,9-9"),
+            String::from("2-4,6-8,3-3"),
+            String::from("5-9"),
+        ];
+        let crews = parse_lines(&lines).unwrap();
+        assert_eq!(
+            crews.iter().map(|crew| crew.len()).collect::<Vec<_>>(),
+            vec![3, 3, 1]
+        );
+        assert_eq!(part1(&crews), 1);
+        assert_eq!(part2(&crews), 1);
+
+        let err = parse_lines(&vec![String::from("1-3,5-7,")]).unwrap_err();
+        assert_eq!(err.expected, "comma-separated ranges like `2-4,6-8`");
+    }
+
+    proptest! {
+        #[test]
+        fn parts_match_section_sets(lines in prop::collection::vec(range_pair(), 1..50)) {
+            let pairs = parse_lines(&lines).unwrap();
+            let sets: Vec<_> = pairs.iter().map(section_sets).collect();
+
+            let contained = sets.iter().filter(|(a, b)| a.is_subset(b) || b.is_subset(a)).count();
+            let overlapping = sets.iter().filter(|(a, b)| !a.is_disjoint(b)).count();
+            prop_assert_eq!(part1(&pairs), contained);
+            prop_assert_eq!(part2(&pairs), overlapping);
+            prop_assert!(contained <= overlapping);
+        }
+    }
+}
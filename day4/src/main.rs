@@ -1,87 +1,83 @@
-use regex::{Captures, Regex};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::ops::Range;
+use aoc_common::{read_parsed_from, Interval};
+use day4::crew::{minimal_cover, multiply_covered, uncovered, Crew};
+use day4::{part1, part2, Day4};
+use std::env;
+use std::process;
 
-fn parse_num(caps: &Captures, label: &str) -> u32 {
-    return caps.name(label).unwrap().as_str().parse::<u32>().unwrap();
+const USAGE: &str = "Usage: day4 [INPUT] [--bound=START-END]";
+
+struct Options {
+    filename: Option<String>,
+    bound: Option<Interval<u32>>,
 }
 
-fn parse_lines(lines: &Vec<String>) -> Vec<(Range<u32>, Range<u32>)> {
-    let re = Regex::new(r"^(?P<s1>\d+)-(?P<e1>\d+),(?P<s2>\d+)-(?P<e2>\d+)").unwrap();
-    return lines
-        .into_iter()
-        .map(|l| {
-            let caps = re.captures(l).unwrap();
-            return (
-                parse_num(&caps, "s1")..parse_num(&caps, "e1"),
-                parse_num(&caps, "s2")..parse_num(&caps, "e2"),
-            );
-        })
-        .collect();
+fn parse_bound(value: &str) -> Option<Interval<u32>> {
+    let (start, end) = value.split_once('-')?;
+    return Interval::try_new(start.parse().ok()?, end.parse().ok()?);
 }
 
-fn part1(lines: &Vec<String>) -> usize {
-    let ranges = parse_lines(lines);
-    return ranges
-        .iter()
-        .filter({
-            |(r1, r2)| {
-                return (r1.start >= r2.start && r1.end <= r2.end)
-                    || (r2.start >= r1.start && r2.end <= r1.end);
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        filename: None,
+        bound: None,
+    };
+
+    for arg in env::args().skip(1) {
+        let (option, value) = arg.split_once('=').unwrap_or((&arg, ""));
+        match option {
+            "--bound" => {
+                let bound =
+                    parse_bound(value).ok_or_else(|| format!("Bad section range `{}`", value))?;
+                options.bound = Some(bound);
             }
-        })
-        .count();
+            _ if arg.starts_with("--") => return Err(format!("Unexpected argument `{}`", arg)),
+            _ if options.filename.is_none() => options.filename = Some(arg),
+            _ => return Err(format!("Unexpected argument `{}`", arg)),
+        }
+    }
+    return Ok(options);
 }
 
-fn part2(lines: &Vec<String>) -> usize {
-    let ranges = parse_lines(lines);
-    return ranges
-        .iter()
-        .filter(|(r1, r2)| r1.end >= r2.start && r2.end >= r1.start)
-        .count();
+// The sections from the crew's first to its last, for when no bound is given.
+fn span(crew: &Crew) -> Interval<u32> {
+    let start = crew.iter().map(|range| range.start()).min().unwrap();
+    let end = crew.iter().map(|range| range.end()).max().unwrap();
+    return Interval::new(start, end);
+}
+
+fn list(ranges: &[Interval<u32>]) -> String {
+    let ranges: Vec<String> = ranges.iter().map(|range| range.to_string()).collect();
+    return ranges.join(",");
 }
 
 fn main() {
-    let file = File::open("input").unwrap();
-    let reader = BufReader::new(file);
-    let lines: Vec<String> = reader.lines().map(|l| l.unwrap()).collect();
+    let options = parse_options().unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    });
+    let ranges = read_parsed_from::<Day4>(options.filename.as_deref());
 
-    let part1_result = part1(&lines);
-    let part2_result = part2(&lines);
+    let part1_result = part1(&ranges);
+    let part2_result = part2(&ranges);
 
     println!("Part 1: {}, Part 2: {}", part1_result, part2_result);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn pt1_test() {
-        let lines = vec![
-            String::from("2-4,6-8"),
-            String::from("2-3,4-5"),
-            String::from("5-7,7-9"),
-            String::from("2-8,3-7"),
-            String::from("6-6,4-6"),
-            String::from("2-6,4-8"),
-        ];
-        let result = part1(&lines);
-        assert_eq!(result, 2);
-    }
+    // Elves whose sections are all covered by the rest of their crew anyway.
+    let spare: usize = ranges
+        .iter()
+        .map(|crew| crew.len() - minimal_cover(crew).len())
+        .sum();
+    println!("Elves not needed to cover their crew's sections: {}", spare);
 
-    #[test]
-    fn pt2_test() {
-        let lines = vec![
-            String::from("2-4,6-8"),
-            String::from("2-3,4-5"),
-            String::from("5-7,7-9"),
-            String::from("2-8,3-7"),
-            String::from("6-6,4-6"),
-            String::from("2-6,4-8"),
-        ];
-        let result = part2(&lines);
-        assert_eq!(result, 4);
+    for (index, crew) in ranges.iter().enumerate() {
+        let bound = options.bound.unwrap_or_else(|| span(crew));
+        let gaps = uncovered(crew, bound);
+        let shared = multiply_covered(crew);
+        if !gaps.is_empty() {
+            println!("Crew {} leaves {} uncovered", index + 1, list(&gaps));
+        }
+        if !shared.is_empty() {
+            println!("Crew {} covers {} more than once", index + 1, list(&shared));
+        }
     }
 }
@@ -0,0 +1,3 @@
+use day5::Day5;
+
+aoc_common::bench_main!(Day5, ["input"]);
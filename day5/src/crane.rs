@@ -0,0 +1,81 @@
+// A model of crane, which decides what order the crates it moves end up in.
+pub trait Crane {
+    fn name(&self) -> String;
+
+    // Given the crates being moved, from the bottom up as they stood on the stack they came from,
+    // the order they end up in on the stack they're moved to, from the bottom up.
+    fn place(&self, lifted: &[char]) -> Vec<char>;
+}
+
+// Moves one crate at a time, so the crates end up in reverse order.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        return String::from("CrateMover 9000");
+    }
+
+    fn place(&self, lifted: &[char]) -> Vec<char> {
+        return lifted.iter().rev().copied().collect();
+    }
+}
+
+// Moves every crate at once, so they keep their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        return String::from("CrateMover 9001");
+    }
+
+    fn place(&self, lifted: &[char]) -> Vec<char> {
+        return lifted.to_vec();
+    }
+}
+
+// Lifts at most `capacity` crates per trip, taking them from the top of the stack. The crates in
+// each trip keep their order, but later trips land on top of earlier ones.
+pub struct LimitedCrane {
+    capacity: usize,
+}
+
+impl LimitedCrane {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "A crane has to lift at least one crate");
+        return LimitedCrane { capacity };
+    }
+}
+
+impl Crane for LimitedCrane {
+    fn name(&self) -> String {
+        return format!("Crane lifting up to {}", self.capacity);
+    }
+
+    fn place(&self, lifted: &[char]) -> Vec<char> {
+        return lifted.rchunks(self.capacity).flatten().copied().collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn limited_crane_test() {
+        let crane = LimitedCrane::new(2);
+        assert_eq!(
+            crane.place(&['A', 'B', 'C', 'D', 'E']),
+            vec!['D', 'E', 'B', 'C', 'A']
+        );
+        assert_eq!(crane.place(&[]), Vec::<char>::new());
+    }
+
+    proptest! {
+        #[test]
+        fn limited_cranes_match_crate_movers(lifted in prop::collection::vec(prop::char::range('A', 'Z'), 0..20)) {
+            prop_assert_eq!(LimitedCrane::new(1).place(&lifted), CrateMover9000.place(&lifted));
+            prop_assert_eq!(LimitedCrane::new(20).place(&lifted), CrateMover9001.place(&lifted));
+        }
+    }
+}
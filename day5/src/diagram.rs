@@ -0,0 +1,180 @@
+use crate::Stacks;
+use aoc_common::ParseError;
+
+// A stack's label in the bottom row of the diagram, and the columns it spans, from 0.
+struct Label {
+    number: usize,
+    first: usize,
+    last: usize,
+}
+
+// Find the stack labels, which have to number the stacks from 1 in order.
+fn parse_labels(line_number: usize, line: &str) -> Result<Vec<Label>, ParseError> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut labels = Vec::new();
+    let mut column = 0;
+
+    while column < chars.len() {
+        if chars[column].is_whitespace() {
+            column += 1;
+            continue;
+        }
+
+        let first = column;
+        while column < chars.len() && !chars[column].is_whitespace() {
+            column += 1;
+        }
+        let offset = line.char_indices().nth(first).unwrap().0;
+        let number = labels.len() + 1;
+        let token: String = chars[first..column].iter().collect();
+        if token.parse::<usize>() != Ok(number) {
+            let expected = format!("stack label {}", number);
+            return Err(ParseError::in_line(line_number, line, offset, expected));
+        }
+
+        labels.push(Label {
+            number,
+            first,
+            last: column - 1,
+        });
+    }
+
+    if labels.is_empty() {
+        return Err(ParseError::new(line_number, 1, "stack labels", ""));
+    }
+    return Ok(labels);
+}
+
+// Parse the drawing of the starting stacks, like:
+//
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+//  1   2   3
+//
+// with each crate's letter above its stack's label, and the stacks labelled from 1. Labels can be
+// more than one digit wide, with the letter over any of them, and lines can have any amount of
+// trailing whitespace.
+pub fn parse_stacks(lines: &[&str]) -> Result<Stacks, ParseError> {
+    let Some((label_line, crate_lines)) = lines.split_last() else {
+        return Err(ParseError::new(1, 1, "a drawing of the stacks", ""));
+    };
+    let labels = parse_labels(lines.len(), label_line)?;
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); labels.len()];
+
+    // Work up from the bottom row, so each crate lands on top of the ones below it.
+    for (index, line) in crate_lines.iter().enumerate().rev() {
+        let line_number = index + 1;
+        let chars: Vec<(usize, char)> = line.char_indices().collect();
+        let mut column = 0;
+
+        while column < chars.len() {
+            let (offset, c) = chars[column];
+            if c.is_whitespace() {
+                column += 1;
+                continue;
+            }
+
+            let letter = match (c, chars.get(column + 1), chars.get(column + 2)) {
+                ('[', Some(&(_, letter)), Some(&(_, ']'))) if !letter.is_whitespace() => letter,
+                _ => {
+                    return Err(ParseError::in_line(
+                        line_number,
+                        line,
+                        offset,
+                        "a crate like `[A]`",
+                    ))
+                }
+            };
+
+            let letter_column = column + 1;
+            let label = labels
+                .iter()
+                .find(|label| label.first <= letter_column && letter_column <= label.last)
+                .ok_or_else(|| {
+                    ParseError::in_line(line_number, line, offset, "a crate above a stack label")
+                })?;
+
+            // Crates can't float above an empty space.
+            let stack = &mut stacks[label.number - 1];
+            if stack.len() != crate_lines.len() - 1 - index {
+                let expected = format!("a crate resting on stack {}", label.number);
+                return Err(ParseError::in_line(line_number, line, offset, expected));
+            }
+            stack.push(letter);
+
+            column += 3;
+        }
+    }
+
+    return Ok(Stacks::new(stacks));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::example_stacks;
+    use proptest::prelude::*;
+
+    fn example() -> Vec<&'static str> {
+        return vec!["    [D]    ", "[N] [C]    ", "[Z] [M] [P]", " 1   2   3 "];
+    }
+
+    #[test]
+    fn example_test() {
+        let stacks = parse_stacks(&example()).unwrap();
+        assert_eq!(stacks, example_stacks());
+
+        // Trailing whitespace doesn't matter.
+        let trimmed: Vec<&str> = example().iter().map(|line| line.trim_end()).collect();
+        assert_eq!(parse_stacks(&trimmed).unwrap(), stacks);
+        let padded = vec!["    [D]         ", "[N] [C]", "[Z] [M] [P]  ", " 1   2   3"];
+        assert_eq!(parse_stacks(&padded).unwrap(), stacks);
+    }
+
+    #[test]
+    fn wide_labels_test() {
+        let lines = vec![
+            "                                        [K]",
+            "[A]                                 [J] [L]",
+            " 1   2   3   4   5   6   7   8   9  10  11 ",
+        ];
+        let stacks = parse_stacks(&lines).unwrap();
+        assert_eq!(stacks.stacks.len(), 11);
+        assert_eq!(stacks.stacks[0], vec!['A']);
+        assert_eq!(stacks.stacks[9], vec!['J']);
+        assert_eq!(stacks.stacks[10], vec!['L', 'K']);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse_stacks(&["[A] {B}", " 1   2 "]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.expected, "a crate like `[A]`");
+
+        let err = parse_stacks(&["  [A]", " 1   2 "]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        assert_eq!(err.expected, "a crate above a stack label");
+
+        let err = parse_stacks(&["[A]", "   ", " 1 "]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "a crate resting on stack 1");
+
+        let err = parse_stacks(&[" 1   3 "]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.expected, "stack label 2");
+    }
+
+    proptest! {
+        #[test]
+        fn drawn_stacks_roundtrip(
+            stacks in prop::collection::vec(prop::collection::vec(prop::char::range('A', 'Z'), 0..6), 1..15),
+            trailing in " {0,3}",
+        ) {
+            let drawn = Stacks::new(stacks.clone()).to_string();
+            let lines: Vec<String> = drawn.lines().map(|line| format!("{}{}", line.trim_end(), trailing)).collect();
+            let lines: Vec<&str> = lines.iter().map(|line| line.as_str()).collect();
+            prop_assert_eq!(parse_stacks(&lines).unwrap().stacks, stacks);
+        }
+    }
+}
@@ -0,0 +1,529 @@
+use aoc_common::{parse_non_empty_lines, parse_num, ParseError, Solution, SolveError};
+use regex::Regex;
+
+mod crane;
+mod diagram;
+mod plan;
+mod render;
+
+pub use crane::{Crane, CrateMover9000, CrateMover9001, LimitedCrane};
+pub use diagram::parse_stacks;
+pub use plan::{plan, PlanError, Target};
+pub use render::{animate, render, Highlight};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Move {
+    pub src: usize,
+    pub dst: usize,
+    pub count: usize,
+}
+
+// Written the way `parse_ops` reads it.
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "move {} from {} to {}", self.count, self.src, self.dst);
+    }
+}
+
+// Why a move can't be made from the current state of the stacks. Stacks are numbered from 1, as
+// in the puzzle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MoveError {
+    NoSuchStack {
+        stack: usize,
+        stacks: usize,
+    },
+    SameStack {
+        stack: usize,
+    },
+    NotEnoughCrates {
+        stack: usize,
+        wanted: usize,
+        available: usize,
+    },
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            MoveError::NoSuchStack { stack, stacks } => {
+                write!(f, "there's no stack {}, only 1 to {}", stack, stacks)
+            }
+            MoveError::SameStack { stack } => {
+                write!(f, "can't move crates from stack {} onto itself", stack)
+            }
+            MoveError::NotEnoughCrates {
+                stack,
+                wanted,
+                available,
+            } => write!(
+                f,
+                "can't move {} crates from stack {}, which only has {}",
+                wanted, stack, available
+            ),
+        };
+    }
+}
+
+// A move that has been made, with the crates it took and the order it left them in, so it can be
+// undone or made again without the crane.
+#[derive(Clone, Debug, PartialEq)]
+struct Step {
+    op: Move,
+    lifted: Vec<char>,
+    placed: Vec<char>,
+}
+
+// The stacks of crates, along with the moves made so far and the moves that have been undone.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stacks {
+    stacks: Vec<Vec<char>>,
+    done: Vec<Step>,
+    undone: Vec<Step>,
+}
+
+impl Stacks {
+    pub fn new(stacks: Vec<Vec<char>>) -> Self {
+        return Stacks {
+            stacks,
+            ..Stacks::default()
+        };
+    }
+
+    // The crates in each stack, from the bottom up.
+    pub fn stacks(&self) -> &[Vec<char>] {
+        return &self.stacks;
+    }
+
+    pub fn check(&self, op: &Move) -> Result<(), MoveError> {
+        for stack in [op.src, op.dst] {
+            if stack == 0 || stack > self.stacks.len() {
+                return Err(MoveError::NoSuchStack {
+                    stack,
+                    stacks: self.stacks.len(),
+                });
+            }
+        }
+        if op.src == op.dst {
+            return Err(MoveError::SameStack { stack: op.src });
+        }
+
+        let available = self.stacks[op.src - 1].len();
+        if op.count > available {
+            return Err(MoveError::NotEnoughCrates {
+                stack: op.src,
+                wanted: op.count,
+                available,
+            });
+        }
+        return Ok(());
+    }
+
+    // Make a move with the given crane. Anything that was undone can't be redone afterwards.
+    pub fn apply(&mut self, crane: &dyn Crane, op: &Move) -> Result<(), MoveError> {
+        self.check(op)?;
+        let src = &self.stacks[op.src - 1];
+        let lifted = src[src.len() - op.count..].to_vec();
+        let placed = crane.place(&lifted);
+
+        let step = Step {
+            op: *op,
+            lifted,
+            placed,
+        };
+        self.redo_step(&step);
+        self.done.push(step);
+        self.undone.clear();
+        return Ok(());
+    }
+
+    // Make each move in turn, stopping at the first one that can't be made, along with its index.
+    pub fn apply_all(&mut self, crane: &dyn Crane, ops: &[Move]) -> Result<(), (usize, MoveError)> {
+        for (index, op) in ops.iter().enumerate() {
+            self.apply(crane, op).map_err(|e| (index, e))?;
+        }
+        return Ok(());
+    }
+
+    fn redo_step(&mut self, step: &Step) {
+        let src = &mut self.stacks[step.op.src - 1];
+        src.truncate(src.len() - step.lifted.len());
+        self.stacks[step.op.dst - 1].extend(&step.placed);
+    }
+
+    // Take back the last move made, returning it, or `None` if there's nothing to undo.
+    pub fn undo(&mut self) -> Option<Move> {
+        let step = self.done.pop()?;
+        let dst = &mut self.stacks[step.op.dst - 1];
+        dst.truncate(dst.len() - step.placed.len());
+        self.stacks[step.op.src - 1].extend(&step.lifted);
+
+        let op = step.op;
+        self.undone.push(step);
+        return Some(op);
+    }
+
+    // Make the last undone move again, returning it, or `None` if there's nothing to redo.
+    pub fn redo(&mut self) -> Option<Move> {
+        let step = self.undone.pop()?;
+        self.redo_step(&step);
+
+        let op = step.op;
+        self.done.push(step);
+        return Some(op);
+    }
+
+    // The moves made so far, in order.
+    pub fn history(&self) -> Vec<Move> {
+        return self.done.iter().map(|step| step.op).collect();
+    }
+
+    pub fn tops(&self) -> String {
+        return self
+            .stacks
+            .iter()
+            .map(|stack| stack.last().copied().unwrap_or(' '))
+            .collect();
+    }
+}
+
+pub fn parse_ops(lines: &Vec<String>) -> Result<Vec<Move>, ParseError> {
+    let re = Regex::new(r"^move (?P<count>\d+) from (?P<src>\d+) to (?P<dst>\d+)$").unwrap();
+    return lines
+        .iter()
+        .enumerate()
+        .map(|(index, l)| {
+            let line = index + 1;
+            let caps = re
+                .captures(l)
+                .ok_or_else(|| ParseError::bad_line(line, l, "`move N from A to B`"))?;
+            let src = parse_num(&caps, "src", line)?;
+            let dst = parse_num(&caps, "dst", line)?;
+            let count = parse_num(&caps, "count", line)?;
+            Ok(Move { src, dst, count })
+        })
+        .collect();
+}
+
+// Point a move that can't be made at the number in its line that's to blame.
+fn move_error(line: usize, text: &str, op: &Move, error: MoveError) -> ParseError {
+    // The words of `move N from A to B`, where they start in the line.
+    let offsets: Vec<usize> = text
+        .split_whitespace()
+        .map(|word| word.as_ptr() as usize - text.as_ptr() as usize)
+        .collect();
+
+    let (word, expected) = match error {
+        MoveError::NoSuchStack { stack, stacks } => (
+            if stack == op.src { 3 } else { 5 },
+            format!("a stack number from 1 to {}", stacks),
+        ),
+        MoveError::SameStack { .. } => (5, String::from("a different stack to move to")),
+        MoveError::NotEnoughCrates {
+            stack, available, ..
+        } => (
+            1,
+            format!(
+                "at most {} crates, as that's all stack {} has",
+                available, stack
+            ),
+        ),
+    };
+    return ParseError::in_line(line, text, offsets[word], expected);
+}
+
+// The moves have to have been checked against the stacks, as `Day5::parse` does.
+pub fn part1(stacks: &mut Stacks, ops: &Vec<Move>) -> String {
+    stacks
+        .apply_all(&CrateMover9000, ops)
+        .expect("The moves should have been checked");
+    return stacks.tops();
+}
+
+pub fn part2(stacks: &mut Stacks, ops: &Vec<Move>) -> String {
+    stacks
+        .apply_all(&CrateMover9001, ops)
+        .expect("The moves should have been checked");
+    return stacks.tops();
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Stacks, Vec<Move>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // The drawing of the stacks comes first, then a blank line, then the moves.
+        let lines: Vec<&str> = input.lines().collect();
+        let split = lines
+            .iter()
+            .position(|line| line.trim().is_empty())
+            .unwrap_or(lines.len());
+        let stacks = parse_stacks(&lines[..split])?;
+
+        let moves = lines.get(split + 1..).unwrap_or_default().join("\n");
+        let ops = parse_non_empty_lines(&moves, parse_ops).map_err(|mut e| {
+            e.line += split + 1;
+            e
+        })?;
+
+        // Make sure every move is possible. Which crane is used doesn't matter, as they all move
+        // the same number of crates.
+        if let Err((index, error)) = stacks.clone().apply_all(&CrateMover9001, &ops) {
+            let (line, text) = lines
+                .iter()
+                .enumerate()
+                .skip(split + 1)
+                .filter(|(_, text)| !text.trim().is_empty())
+                .nth(index)
+                .unwrap();
+            return Err(move_error(line + 1, text, &ops[index], error));
+        }
+        return Ok((stacks, ops));
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        let mut stacks = input.0.clone();
+        return Ok(part1(&mut stacks, &input.1));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        let mut stacks = input.0.clone();
+        return Ok(part2(&mut stacks, &input.1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+
+    // Some stacks of crates, with a list of moves that are valid to apply to them.
+    fn stacks_and_moves() -> impl Strategy<Value = (Stacks, Vec<String>)> {
+        let stacks = prop::collection::vec(
+            prop::collection::vec(prop::char::range('A', 'Z'), 0..8),
+            2..6,
+        );
+        let choices = prop::collection::vec(any::<(Index, Index, Index)>(), 0..30);
+        return (stacks, choices).prop_map(|(stacks, choices)| {
+            let mut sizes: Vec<usize> = stacks.iter().map(|stack| stack.len()).collect();
+            let mut moves = Vec::new();
+            for (src, dst, count) in choices {
+                let sources: Vec<usize> = (0..sizes.len()).filter(|&i| sizes[i] > 0).collect();
+                if sources.is_empty() {
+                    break;
+                }
+                let src = sources[src.index(sources.len())];
+                let dst = (src + 1 + dst.index(sizes.len() - 1)) % sizes.len();
+                let count = count.index(sizes[src]) + 1;
+
+                sizes[src] -= count;
+                sizes[dst] += count;
+                moves.push(format!("move {} from {} to {}", count, src + 1, dst + 1));
+            }
+            return (Stacks::new(stacks), moves);
+        });
+    }
+
+    // The stacks from the puzzle's example, for every module's tests.
+    pub(crate) fn example_stacks() -> Stacks {
+        return Stacks::new(vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+    }
+
+    fn sorted_crates(stacks: &Stacks) -> Vec<char> {
+        let mut crates: Vec<char> = stacks.stacks.concat();
+        crates.sort();
+        return crates;
+    }
+
+    #[test]
+    fn pt1_test() {
+        let mut stacks = example_stacks();
+        let lines: Vec<String> = vec![
+            String::from("move 1 from 2 to 1"),
+            String::from("move 3 from 1 to 3"),
+            String::from("move 2 from 2 to 1"),
+            String::from("move 1 from 1 to 2"),
+        ];
+
+        let ops = parse_ops(&lines).unwrap();
+        let result = part1(&mut stacks, &ops);
+        assert_eq!(result.as_str(), "CMZ");
+    }
+
+    #[test]
+    fn pt2_test() {
+        let mut stacks = example_stacks();
+        let lines: Vec<String> = vec![
+            String::from("move 1 from 2 to 1"),
+            String::from("move 3 from 1 to 3"),
+            String::from("move 2 from 2 to 1"),
+            String::from("move 1 from 1 to 2"),
+        ];
+
+        let ops = parse_ops(&lines).unwrap();
+        let result = part2(&mut stacks, &ops);
+        assert_eq!(result.as_str(), "MCD");
+    }
+
+    #[test]
+    fn parse_test() {
+        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\n";
+        let (stacks, ops) = Day5::parse(input).unwrap();
+        assert_eq!(stacks, example_stacks());
+        assert_eq!(ops.len(), 2);
+
+        // Errors in the moves point at their line in the whole input.
+        let err = Day5::parse(&input.replace("move 3", "mvoe 3")).unwrap_err();
+        assert_eq!(err.line, 7);
+        let err = Day5::parse("move 1 from 2 to 1\n").unwrap_err();
+        assert_eq!(err.expected, "stack label 1");
+
+        // So do moves that can't be made.
+        let err = Day5::parse(&input.replace("move 3 from 1", "move 4 from 1")).unwrap_err();
+        assert_eq!((err.line, err.column), (7, 6));
+        assert_eq!(err.expected, "at most 3 crates, as that's all stack 1 has");
+        let err = Day5::parse(&input.replace("to 3", "to 4")).unwrap_err();
+        assert_eq!((err.line, err.column), (7, 18));
+        assert_eq!(err.expected, "a stack number from 1 to 3");
+    }
+
+    #[test]
+    fn move_error_test() {
+        let mut stacks = Stacks::new(vec![vec!['A'], vec![]]);
+        let op = |count, src, dst| Move { src, dst, count };
+        assert_eq!(
+            stacks.apply(&CrateMover9000, &op(1, 2, 1)),
+            Err(MoveError::NotEnoughCrates {
+                stack: 2,
+                wanted: 1,
+                available: 0
+            })
+        );
+        assert_eq!(
+            stacks.apply(&CrateMover9000, &op(1, 1, 3)),
+            Err(MoveError::NoSuchStack {
+                stack: 3,
+                stacks: 2
+            })
+        );
+        assert_eq!(
+            stacks.apply(&CrateMover9000, &op(1, 1, 1)),
+            Err(MoveError::SameStack { stack: 1 })
+        );
+        assert_eq!(
+            stacks.apply_all(&CrateMover9000, &[op(1, 1, 2), op(2, 2, 1)]),
+            Err((
+                1,
+                MoveError::NotEnoughCrates {
+                    stack: 2,
+                    wanted: 2,
+                    available: 1
+                }
+            ))
+        );
+        assert_eq!(stacks.history(), vec![op(1, 1, 2)]);
+        assert_eq!(
+            MoveError::NotEnoughCrates {
+                stack: 2,
+                wanted: 2,
+                available: 1
+            }
+            .to_string(),
+            "can't move 2 crates from stack 2, which only has 1"
+        );
+    }
+
+    #[test]
+    fn undo_redo_test() {
+        let mut stacks = example_stacks();
+        let start = stacks.clone();
+        let crane = LimitedCrane::new(2);
+        let op = Move {
+            src: 2,
+            dst: 3,
+            count: 3,
+        };
+        stacks.apply(&crane, &op).unwrap();
+        assert_eq!(stacks.stacks[2], vec!['P', 'C', 'D', 'M']);
+
+        assert_eq!(stacks.undo(), Some(op));
+        assert_eq!(stacks.undo(), None);
+        assert_eq!(stacks.stacks, start.stacks);
+        assert_eq!(stacks.redo(), Some(op));
+        assert_eq!(stacks.redo(), None);
+        assert_eq!(stacks.tops(), "N M");
+
+        // A new move forgets anything that was undone.
+        stacks.undo();
+        stacks
+            .apply(
+                &crane,
+                &Move {
+                    src: 1,
+                    dst: 3,
+                    count: 1,
+                },
+            )
+            .unwrap();
+        assert_eq!(stacks.redo(), None);
+    }
+
+    proptest! {
+        #[test]
+        fn undo_and_redo_retrace_the_moves((stacks, lines) in stacks_and_moves(), capacity in 1..4usize) {
+            let ops = parse_ops(&lines).unwrap();
+            let mut moved = stacks.clone();
+            let crane = LimitedCrane::new(capacity);
+            let mut states = vec![moved.stacks.clone()];
+            for op in &ops {
+                moved.apply(&crane, op).unwrap();
+                states.push(moved.stacks.clone());
+            }
+            prop_assert_eq!(moved.history(), ops.clone());
+
+            for state in states.iter().rev().skip(1) {
+                prop_assert!(moved.undo().is_some());
+                prop_assert_eq!(&moved.stacks, state);
+            }
+            prop_assert_eq!(moved.undo(), None);
+            for state in states.iter().skip(1) {
+                prop_assert!(moved.redo().is_some());
+                prop_assert_eq!(&moved.stacks, state);
+            }
+            prop_assert_eq!(moved.redo(), None);
+        }
+
+        #[test]
+        fn moves_keep_every_crate((stacks, lines) in stacks_and_moves()) {
+            let ops = parse_ops(&lines).unwrap();
+            let (mut one_by_one, mut in_order) = (stacks.clone(), stacks.clone());
+            part1(&mut one_by_one, &ops);
+            part2(&mut in_order, &ops);
+
+            prop_assert_eq!(sorted_crates(&one_by_one), sorted_crates(&stacks));
+            prop_assert_eq!(sorted_crates(&in_order), sorted_crates(&stacks));
+        }
+
+        #[test]
+        fn part1_moves_one_crate_at_a_time((stacks, lines) in stacks_and_moves()) {
+            let ops = parse_ops(&lines).unwrap();
+            let single_ops: Vec<Move> = ops
+                .iter()
+                .flat_map(|op| (0..op.count).map(|_| Move { src: op.src, dst: op.dst, count: 1 }))
+                .collect();
+
+            let (mut batched, mut single) = (stacks.clone(), stacks.clone());
+            prop_assert_eq!(part1(&mut batched, &ops), part1(&mut single, &single_ops));
+            prop_assert_eq!(batched.stacks, single.stacks);
+
+            // Moving a single crate is the same for both cranes.
+            let (mut one_by_one, mut in_order) = (stacks.clone(), stacks);
+            prop_assert_eq!(part1(&mut one_by_one, &single_ops), part2(&mut in_order, &single_ops));
+        }
+    }
+}
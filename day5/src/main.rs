@@ -1,114 +1,112 @@
-use regex::{Captures, Regex};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-#[derive(Clone, Copy, PartialEq)]
-enum StackMoveOrder {
-    Reversed,
-    Ordered,
+use aoc_common::{read_input, read_parsed_from};
+use day5::{
+    animate, parse_stacks, part1, part2, plan, Crane, CrateMover9000, CrateMover9001, Day5,
+    LimitedCrane, Target,
+};
+use std::env;
+use std::io;
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: day5 [INPUT] [--crane=9000|9001] [--capacity=N] \
+    [--plan=TOPS | --plan-file=DRAWING] [--max-states=N] [--animate] [--delay=MS]";
+
+struct Options {
+    filename: Option<String>,
+    crane: Box<dyn Crane>,
+    target: Option<Target>,
+    max_states: usize,
+    animate: bool,
+    delay: Duration,
 }
 
-struct Move {
-    src: usize,
-    dst: usize,
-    count: usize,
+fn parse_number<T: std::str::FromStr>(option: &str, value: &str) -> Result<T, String> {
+    return value
+        .parse()
+        .map_err(|_| format!("Bad {} `{}`", option, value));
 }
 
-#[derive(Clone)]
-struct Stacks {
-    stacks: Vec<Vec<char>>,
+// Read the target arrangement from a drawing of the stacks, like the top of the puzzle input.
+fn read_target(filename: &str) -> Result<Target, String> {
+    let drawing = read_input(filename);
+    let lines: Vec<&str> = drawing.lines().collect();
+    let stacks = parse_stacks(&lines).map_err(|e| e.annotate(&drawing, filename))?;
+    return Ok(Target::Arrangement(stacks.stacks().to_vec()));
 }
 
-impl Stacks {
-    fn exec_move(&mut self, src: usize, dst: usize, count: usize, order: StackMoveOrder) {
-        let mut moving: Vec<char> = Vec::with_capacity(count);
-        for _ in 0..count {
-            moving.push(self.stacks[src - 1].pop().unwrap())
-        }
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        filename: None,
+        crane: Box::new(CrateMover9000),
+        target: None,
+        max_states: 200_000,
+        animate: false,
+        delay: Duration::from_millis(200),
+    };
 
-        if order == StackMoveOrder::Reversed {
-            for c in moving {
-                self.stacks[dst - 1].push(c);
+    for arg in env::args().skip(1) {
+        let (option, value) = arg.split_once('=').unwrap_or((&arg, ""));
+        match option {
+            "--crane" if value == "9000" => options.crane = Box::new(CrateMover9000),
+            "--crane" if value == "9001" => options.crane = Box::new(CrateMover9001),
+            "--capacity" => {
+                let capacity = parse_number("capacity", value)?;
+                if capacity == 0 {
+                    return Err(String::from("A crane has to lift at least one crate"));
+                }
+                options.crane = Box::new(LimitedCrane::new(capacity));
             }
-        } else {
-            for &c in moving.iter().rev() {
-                self.stacks[dst - 1].push(c);
+            "--plan" => options.target = Some(Target::tops(value)),
+            "--plan-file" => options.target = Some(read_target(value)?),
+            "--max-states" => options.max_states = parse_number("state limit", value)?,
+            "--animate" => options.animate = true,
+            "--delay" => options.delay = Duration::from_millis(parse_number("delay", value)?),
+            _ if arg.starts_with("--") || options.filename.is_some() => {
+                return Err(format!("Unexpected argument `{}`", arg));
             }
+            _ => options.filename = Some(arg),
         }
     }
+    return Ok(options);
+}
 
-    fn move_op(&mut self, op: &Move, order: StackMoveOrder) {
-        self.exec_move(op.src, op.dst, op.count, order);
-    }
-
-    fn move_ops(&mut self, ops: &Vec<Move>, order: StackMoveOrder) {
-        for op in ops {
-            self.move_op(op, order);
+fn main() {
+    let options = parse_options().unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    });
+
+    let (stacks, ops) = read_parsed_from::<Day5>(options.filename.as_deref());
+
+    // Search for moves that reach the target instead of using the ones in the input, and print
+    // them in the same format, unless they're going to be animated.
+    let mut ops = ops;
+    if let Some(target) = &options.target {
+        ops = plan(&stacks, target, &*options.crane, options.max_states).unwrap_or_else(|e| {
+            eprintln!("No plan found: {}", e);
+            process::exit(1);
+        });
+        if !options.animate {
+            for op in &ops {
+                println!("{}", op);
+            }
+            return;
         }
     }
 
-    fn tops(&self) -> String {
-        return self
-            .stacks
-            .iter()
-            .map(|stack| stack.last().map(|&c| c).unwrap_or(' '))
-            .collect();
+    // Replay the moves on the terminal, instead of just solving.
+    if options.animate {
+        let mut stacks = stacks;
+        animate(
+            &mut io::stdout().lock(),
+            &mut stacks,
+            &*options.crane,
+            &ops,
+            options.delay,
+        )
+        .unwrap_or_else(|e| panic!("Failed to animate: {}", e));
+        return;
     }
-}
-
-fn parse_num(caps: &Captures, label: &str) -> usize {
-    return caps.name(label).unwrap().as_str().parse::<usize>().unwrap();
-}
-
-fn parse_ops(lines: &Vec<String>) -> Vec<Move> {
-    let re = Regex::new(r"^move (?P<count>\d+) from (?P<src>\d+) to (?P<dst>\d+)").unwrap();
-    return lines
-        .iter()
-        .map(|l| {
-            let caps = re.captures(l).unwrap();
-            let src = parse_num(&caps, "src");
-            let dst = parse_num(&caps, "dst");
-            let count = parse_num(&caps, "count");
-            Move {
-                src: src,
-                dst: dst,
-                count: count,
-            }
-        })
-        .collect();
-}
-
-fn part1(stacks: &mut Stacks, ops: &Vec<Move>) -> String {
-    stacks.move_ops(&ops, StackMoveOrder::Reversed);
-    return stacks.tops();
-}
-
-fn part2(stacks: &mut Stacks, ops: &Vec<Move>) -> String {
-    stacks.move_ops(&ops, StackMoveOrder::Ordered);
-    return stacks.tops();
-}
-
-fn main() {
-    let file = File::open("input").unwrap();
-    let reader = BufReader::new(file);
-    let lines: Vec<String> = reader.lines().map(|l| l.unwrap()).collect();
-    let ops = parse_ops(&lines);
-
-    // Hardcoding the stacks layout here, so we're assuming it is removed from
-    // the input file.
-    let stacks = Stacks {
-        stacks: vec![
-            vec!['Z', 'P', 'M', 'H', 'R'],
-            vec!['P', 'C', 'J', 'B'],
-            vec!['S', 'N', 'H', 'G', 'L', 'C', 'D'],
-            vec!['F', 'T', 'M', 'D', 'Q', 'S', 'R', 'L'],
-            vec!['F', 'S', 'P', 'Q', 'B', 'T', 'Z', 'M'],
-            vec!['T', 'F', 'S', 'Z', 'B', 'G'],
-            vec!['N', 'R', 'V'],
-            vec!['P', 'G', 'L', 'T', 'R', 'D', 'V', 'C', 'M'],
-            vec!['W', 'Q', 'N', 'J', 'F', 'M', 'L'],
-        ],
-    };
 
     // Clone the stacks so we get the same start for part 1 and 2.
     let mut pt1_stacks = stacks.clone();
@@ -118,42 +116,3 @@ fn main() {
 
     println!("Part 1: {}, Part 2: {}", pt1_result, pt2_result);
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn pt1_test() {
-        let mut stacks = Stacks {
-            stacks: vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']],
-        };
-        let lines: Vec<String> = vec![
-            String::from("move 1 from 2 to 1"),
-            String::from("move 3 from 1 to 3"),
-            String::from("move 2 from 2 to 1"),
-            String::from("move 1 from 1 to 2"),
-        ];
-
-        let ops = parse_ops(&lines);
-        let result = part1(&mut stacks, &ops);
-        assert_eq!(result.as_str(), "CMZ");
-    }
-
-    #[test]
-    fn pt2_test() {
-        let mut stacks = Stacks {
-            stacks: vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']],
-        };
-        let lines: Vec<String> = vec![
-            String::from("move 1 from 2 to 1"),
-            String::from("move 3 from 1 to 3"),
-            String::from("move 2 from 2 to 1"),
-            String::from("move 1 from 1 to 2"),
-        ];
-
-        let ops = parse_ops(&lines);
-        let result = part2(&mut stacks, &ops);
-        assert_eq!(result.as_str(), "MCD");
-    }
-}
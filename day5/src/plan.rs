@@ -0,0 +1,341 @@
+use crate::{Crane, Move, Stacks};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+
+// What a plan should leave the stacks looking like.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Target {
+    // The crate on top of each stack, like the puzzle's answers, with `None` for an empty stack.
+    Tops(Vec<Option<char>>),
+
+    // Every crate in every stack, from the bottom up.
+    Arrangement(Vec<Vec<char>>),
+}
+
+impl Target {
+    // Tops written like the puzzle's answers, with a space for an empty stack.
+    pub fn tops(letters: &str) -> Self {
+        return Target::Tops(
+            letters
+                .chars()
+                .map(|c| if c == ' ' { None } else { Some(c) })
+                .collect(),
+        );
+    }
+
+    fn stack_count(&self) -> usize {
+        return match self {
+            Target::Tops(tops) => tops.len(),
+            Target::Arrangement(stacks) => stacks.len(),
+        };
+    }
+
+    fn matches(&self, stacks: &[Vec<char>], index: usize) -> bool {
+        return match self {
+            Target::Tops(tops) => stacks[index].last().copied() == tops[index],
+            Target::Arrangement(target) => stacks[index] == target[index],
+        };
+    }
+
+    // Every move changes two stacks, so at least half the stacks that are wrong need a move.
+    fn moves_needed(&self, stacks: &[Vec<char>]) -> usize {
+        let wrong = (0..stacks.len())
+            .filter(|&index| !self.matches(stacks, index))
+            .count();
+        return wrong.div_ceil(2);
+    }
+
+    // Whether the target could be made from these crates at all, ignoring how to get there.
+    fn has_crates(&self, stacks: &[Vec<char>]) -> bool {
+        let mut crates: Vec<char> = stacks.concat();
+        crates.sort_unstable();
+        return match self {
+            Target::Tops(tops) => {
+                let mut wanted: Vec<char> = tops.iter().flatten().copied().collect();
+                wanted.sort_unstable();
+
+                // Each wanted top needs a crate of its own. Every crate has to go somewhere, so if
+                // there are any crates, at least one stack must end up with a top.
+                let mut available = crates.iter();
+                let found = wanted
+                    .iter()
+                    .all(|c| available.by_ref().any(|crate_| crate_ == c));
+                found && (crates.is_empty() || !wanted.is_empty())
+            }
+            Target::Arrangement(target) => {
+                let mut wanted = target.concat();
+                wanted.sort_unstable();
+                wanted == crates
+            }
+        };
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlanError {
+    StackCount { stacks: usize, target: usize },
+    WrongCrates,
+    Unreachable,
+    TooManyStates { limit: usize },
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            PlanError::StackCount { stacks, target } => write!(
+                f,
+                "the target has {} stacks, but there are {}",
+                target, stacks
+            ),
+            PlanError::WrongCrates => {
+                write!(f, "the target can't be made from the crates there are")
+            }
+            PlanError::Unreachable => write!(f, "the crane can't reach the target"),
+            PlanError::TooManyStates { limit } => {
+                write!(f, "gave up looking for a plan after {} arrangements", limit)
+            }
+        };
+    }
+}
+
+// An arrangement of the stacks found while searching, and the move that first reached it.
+struct Node {
+    stacks: Vec<Vec<char>>,
+    parent: Option<(usize, Move)>,
+}
+
+// Find the fewest moves that take the stacks to the target with the given crane, looking at no
+// more than `max_states` arrangements along the way.
+//
+// This is an A* search over arrangements, estimating the moves still needed from how many stacks
+// are wrong. A move only changes two stacks, so the estimate never goes down by more than one per
+// move, and the first plan found is as short as possible.
+pub fn plan(
+    start: &Stacks,
+    target: &Target,
+    crane: &dyn Crane,
+    max_states: usize,
+) -> Result<Vec<Move>, PlanError> {
+    let start = start.stacks().to_vec();
+    if start.len() != target.stack_count() {
+        return Err(PlanError::StackCount {
+            stacks: start.len(),
+            target: target.stack_count(),
+        });
+    }
+    if !target.has_crates(&start) {
+        return Err(PlanError::WrongCrates);
+    }
+
+    let mut best: HashMap<Vec<Vec<char>>, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse((target.moves_needed(&start), 0, 0))]);
+    let mut nodes = vec![Node {
+        stacks: start,
+        parent: None,
+    }];
+
+    while let Some(Reverse((_, moves, id))) = queue.pop() {
+        let stacks = &nodes[id].stacks;
+        if best[stacks] < moves {
+            continue;
+        }
+        if (0..stacks.len()).all(|index| target.matches(stacks, index)) {
+            return Ok(moves_to(&nodes, id));
+        }
+
+        let mut next = Vec::new();
+        for src in 0..stacks.len() {
+            for dst in (0..stacks.len()).filter(|&dst| dst != src) {
+                for count in 1..=stacks[src].len() {
+                    let mut moved = stacks.clone();
+                    let lifted = moved[src].split_off(stacks[src].len() - count);
+                    moved[dst].extend(crane.place(&lifted));
+
+                    let op = Move {
+                        src: src + 1,
+                        dst: dst + 1,
+                        count,
+                    };
+                    next.push((moved, op));
+                }
+            }
+        }
+
+        for (moved, op) in next {
+            if best.get(&moved).is_some_and(|&seen| seen <= moves + 1) {
+                continue;
+            }
+            if nodes.len() == max_states {
+                return Err(PlanError::TooManyStates { limit: max_states });
+            }
+
+            best.insert(moved.clone(), moves + 1);
+            let estimate = moves + 1 + target.moves_needed(&moved);
+            queue.push(Reverse((estimate, moves + 1, nodes.len())));
+            nodes.push(Node {
+                stacks: moved,
+                parent: Some((id, op)),
+            });
+        }
+    }
+
+    return Err(PlanError::Unreachable);
+}
+
+// The moves that lead from the start to a node, in order.
+fn moves_to(nodes: &[Node], mut id: usize) -> Vec<Move> {
+    let mut moves = Vec::new();
+    while let Some((parent, op)) = nodes[id].parent {
+        moves.push(op);
+        id = parent;
+    }
+    moves.reverse();
+    return moves;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::example_stacks;
+    use crate::{parse_ops, CrateMover9000, CrateMover9001, LimitedCrane};
+    use proptest::prelude::*;
+
+    // Feed a plan back through the move parser, and make the moves.
+    fn replay(start: &Stacks, crane: &dyn Crane, moves: &[Move]) -> Stacks {
+        let lines: Vec<String> = moves.iter().map(|op| op.to_string()).collect();
+        let mut stacks = start.clone();
+        stacks
+            .apply_all(crane, &parse_ops(&lines).unwrap())
+            .unwrap();
+        return stacks;
+    }
+
+    #[test]
+    fn tops_test() {
+        // The puzzle's moves take four to get to its answers, but there are quicker ways.
+        let start = example_stacks();
+        let moves = plan(&start, &Target::tops("CMZ"), &CrateMover9000, 100_000).unwrap();
+        assert_eq!(moves.len(), 2);
+        assert_eq!(replay(&start, &CrateMover9000, &moves).tops(), "CMZ");
+
+        let moves = plan(&start, &Target::tops("MCD"), &CrateMover9001, 100_000).unwrap();
+        assert_eq!(moves.len(), 3);
+        assert_eq!(replay(&start, &CrateMover9001, &moves).tops(), "MCD");
+
+        assert_eq!(
+            plan(&start, &Target::tops("NDP"), &CrateMover9000, 100).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn arrangement_test() {
+        let start = example_stacks();
+        let target = Target::Arrangement(vec![vec![], vec!['D', 'C', 'M'], vec!['P', 'Z', 'N']]);
+        let moves = plan(&start, &target, &CrateMover9001, 100_000).unwrap();
+        assert_eq!(
+            replay(&start, &CrateMover9001, &moves).stacks(),
+            &[vec![], vec!['D', 'C', 'M'], vec!['P', 'Z', 'N']]
+        );
+
+        // The CrateMover 9000 reverses a stack by moving it over in one go, but with only one
+        // stack it has nowhere to move anything.
+        let start = Stacks::new(vec![vec!['A', 'B'], vec![]]);
+        let target = Target::Arrangement(vec![vec![], vec!['B', 'A']]);
+        let moves = plan(&start, &target, &CrateMover9000, 100).unwrap();
+        assert_eq!(
+            moves.iter().map(|op| op.to_string()).collect::<Vec<_>>(),
+            vec!["move 2 from 1 to 2"]
+        );
+        let start = Stacks::new(vec![vec!['A', 'B']]);
+        let target = Target::Arrangement(vec![vec!['B', 'A']]);
+        assert_eq!(
+            plan(&start, &target, &CrateMover9000, 100),
+            Err(PlanError::Unreachable)
+        );
+    }
+
+    #[test]
+    fn plan_error_test() {
+        let start = example_stacks();
+        let crane = CrateMover9000;
+        assert_eq!(
+            plan(&start, &Target::tops("AB"), &crane, 100),
+            Err(PlanError::StackCount {
+                stacks: 3,
+                target: 2
+            })
+        );
+        assert_eq!(
+            plan(&start, &Target::tops("ZZD"), &crane, 100),
+            Err(PlanError::WrongCrates)
+        );
+        assert_eq!(
+            plan(&start, &Target::tops("   "), &crane, 100),
+            Err(PlanError::WrongCrates)
+        );
+        assert_eq!(
+            plan(&start, &Target::tops("PZD"), &crane, 10),
+            Err(PlanError::TooManyStates { limit: 10 })
+        );
+    }
+
+    // Every arrangement of up to four crates over three stacks, the shortest plan to each of them,
+    // found by a plain breadth-first search.
+    fn shortest_plans(start: &Stacks, crane: &dyn Crane) -> HashMap<Vec<Vec<char>>, usize> {
+        let mut distances = HashMap::from([(start.stacks().to_vec(), 0)]);
+        let mut frontier = vec![start.stacks().to_vec()];
+        let mut moves = 0;
+        while !frontier.is_empty() {
+            moves += 1;
+            let mut next = Vec::new();
+            for stacks in frontier {
+                for src in 0..stacks.len() {
+                    for dst in (0..stacks.len()).filter(|&dst| dst != src) {
+                        for count in 1..=stacks[src].len() {
+                            let mut moved = stacks.clone();
+                            let lifted = moved[src].split_off(stacks[src].len() - count);
+                            moved[dst].extend(crane.place(&lifted));
+                            if !distances.contains_key(&moved) {
+                                distances.insert(moved.clone(), moves);
+                                next.push(moved);
+                            }
+                        }
+                    }
+                }
+            }
+            frontier = next;
+        }
+        return distances;
+    }
+
+    proptest! {
+        #[test]
+        fn plans_are_shortest(
+            stacks in prop::collection::vec(prop::collection::vec(prop::char::range('A', 'C'), 0..3), 3),
+            capacity in 1..4usize,
+            pick in any::<prop::sample::Index>(),
+        ) {
+            let start = Stacks::new(stacks);
+            let crane = LimitedCrane::new(capacity);
+            let distances = shortest_plans(&start, &crane);
+
+            let mut reachable: Vec<(&Vec<Vec<char>>, &usize)> = distances.iter().collect();
+            reachable.sort();
+            let (goal, &distance) = reachable[pick.index(reachable.len())];
+
+            let moves = plan(&start, &Target::Arrangement(goal.clone()), &crane, 100_000).unwrap();
+            prop_assert_eq!(moves.len(), distance);
+            let reached = replay(&start, &crane, &moves);
+            prop_assert_eq!(reached.stacks(), goal.as_slice());
+
+            // Aiming for just the tops can only be quicker.
+            let tops = Target::Tops(goal.iter().map(|stack| stack.last().copied()).collect());
+            let moves = plan(&start, &tops, &crane, 100_000).unwrap();
+            prop_assert!(moves.len() <= distance);
+            let end = replay(&start, &crane, &moves);
+            prop_assert!((0..3).all(|index| tops.matches(end.stacks(), index)));
+        }
+    }
+}
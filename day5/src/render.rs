@@ -0,0 +1,209 @@
+use crate::{Crane, Move, Stacks};
+use std::fmt;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+// Terminal escape codes: bold, inverted text for the highlighted crates, and clearing the screen
+// between frames of an animation.
+const HIGHLIGHT: &str = "\x1b[1;7m";
+const RESET: &str = "\x1b[0m";
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+// The top `count` crates of a stack, numbered from 1, to pick out when drawing.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Highlight {
+    pub stack: usize,
+    pub count: usize,
+}
+
+// Draw the stacks the way the puzzle does, a line at a time from the top down, with every line
+// padded to the same width and the stack labels along the bottom.
+pub fn render(stacks: &[Vec<char>], highlight: Option<Highlight>) -> Vec<String> {
+    // Stacks are three characters wide, unless their labels need more room.
+    let width = stacks.len().to_string().len().max(3);
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|row| {
+            let cells: Vec<String> = stacks
+                .iter()
+                .enumerate()
+                .map(|(index, stack)| {
+                    let Some(c) = stack.get(row) else {
+                        return " ".repeat(width);
+                    };
+                    let picked = highlight
+                        .is_some_and(|h| h.stack == index + 1 && row + h.count >= stack.len());
+                    let (start, end) = if picked { (HIGHLIGHT, RESET) } else { ("", "") };
+                    return format!("{}[{}]{}{}", start, c, end, " ".repeat(width - 3));
+                })
+                .collect();
+            return cells.join(" ");
+        })
+        .collect();
+
+    let labels: Vec<String> = (1..=stacks.len())
+        .map(|number| format!("{:^width$}", number))
+        .collect();
+    lines.push(labels.join(" "));
+    return lines;
+}
+
+impl fmt::Display for Stacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in render(&self.stacks, None) {
+            writeln!(f, "{}", line)?;
+        }
+        return Ok(());
+    }
+}
+
+fn draw_frame(
+    out: &mut impl Write,
+    caption: &str,
+    stacks: &Stacks,
+    highlight: Option<Highlight>,
+    delay: Duration,
+) -> io::Result<()> {
+    write!(out, "{}{}\n\n", CLEAR, caption)?;
+    for line in render(&stacks.stacks, highlight) {
+        writeln!(out, "{}", line)?;
+    }
+    out.flush()?;
+    thread::sleep(delay);
+    return Ok(());
+}
+
+// Replay the moves on the stacks one at a time with the given crane, drawing each move twice: once
+// with the crates about to be lifted highlighted, and once with them highlighted where they've
+// landed. Each frame stays up for `delay`. A move that can't be made stops the animation with an
+// `InvalidInput` error.
+pub fn animate(
+    out: &mut impl Write,
+    stacks: &mut Stacks,
+    crane: &dyn Crane,
+    ops: &[Move],
+    delay: Duration,
+) -> io::Result<()> {
+    for (index, op) in ops.iter().enumerate() {
+        let caption = format!(
+            "{}, move {} of {}: move {} from {} to {}",
+            crane.name(),
+            index + 1,
+            ops.len(),
+            op.count,
+            op.src,
+            op.dst
+        );
+
+        stacks.check(op).map_err(|e| {
+            let message = format!("move {}: {}", index + 1, e);
+            io::Error::new(io::ErrorKind::InvalidInput, message)
+        })?;
+        let lifted = Highlight {
+            stack: op.src,
+            count: op.count,
+        };
+        draw_frame(out, &caption, stacks, Some(lifted), delay)?;
+
+        stacks
+            .apply(crane, op)
+            .expect("The move was checked before drawing it");
+        let placed = Highlight {
+            stack: op.dst,
+            count: op.count,
+        };
+        draw_frame(out, &caption, stacks, Some(placed), delay)?;
+    }
+
+    let caption = format!("{}, done after {} moves", crane.name(), ops.len());
+    return draw_frame(out, &caption, stacks, None, Duration::ZERO);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::example_stacks;
+    use crate::{parse_stacks, CrateMover9000};
+
+    #[test]
+    fn render_test() {
+        assert_eq!(
+            example_stacks().to_string(),
+            "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n"
+        );
+
+        let lines = render(
+            &example_stacks().stacks,
+            Some(Highlight { stack: 2, count: 2 }),
+        );
+        assert_eq!(
+            lines,
+            vec![
+                "    \x1b[1;7m[D]\x1b[0m    ",
+                "[N] \x1b[1;7m[C]\x1b[0m    ",
+                "[Z] [M] [P]",
+                " 1   2   3 ",
+            ]
+        );
+
+        // An empty crate yard is just the labels.
+        assert_eq!(Stacks::new(vec![vec![], vec![]]).to_string(), " 1   2 \n");
+    }
+
+    #[test]
+    fn wide_render_test() {
+        let mut stacks = vec![vec![]; 1000];
+        stacks[999].push('A');
+        let stacks = Stacks::new(stacks);
+        let drawn = stacks.to_string();
+        let lines: Vec<&str> = drawn.lines().collect();
+        assert!(lines[0].ends_with("[A] "));
+        assert!(lines[1].ends_with(" 999  1000"));
+        assert_eq!(parse_stacks(&lines).unwrap(), stacks);
+    }
+
+    #[test]
+    fn animate_test() {
+        let mut stacks = example_stacks();
+        let ops = [
+            Move {
+                src: 2,
+                dst: 1,
+                count: 1,
+            },
+            Move {
+                src: 1,
+                dst: 3,
+                count: 3,
+            },
+        ];
+
+        let mut out = Vec::new();
+        animate(&mut out, &mut stacks, &CrateMover9000, &ops, Duration::ZERO).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches(CLEAR).count(), 5);
+        assert!(out.contains("CrateMover 9000, move 2 of 2: move 3 from 1 to 3"));
+        assert!(out.ends_with(&format!("done after 2 moves\n\n{}", stacks)));
+        assert_eq!(stacks.tops(), " CZ");
+
+        // A move that can't be made is an error, after the moves before it.
+        let mut stacks = example_stacks();
+        let err = animate(
+            &mut Vec::new(),
+            &mut stacks,
+            &CrateMover9000,
+            &[ops[0], ops[0], ops[0], ops[0]],
+            Duration::ZERO,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "move 4: can't move 1 crates from stack 2, which only has 0"
+        );
+        assert_eq!(stacks.history().len(), 3);
+    }
+}
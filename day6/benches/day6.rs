@@ -0,0 +1,3 @@
+use day6::Day6;
+
+aoc_common::bench_main!(Day6, ["input"]);
@@ -0,0 +1,109 @@
+use aoc_common::{ParseError, Solution, SolveError};
+
+mod markers;
+mod stream;
+
+pub use markers::{byte_markers, char_markers, markers, Markers, Overlap};
+pub use stream::{find_marker_in, find_markers_in, MarkerDetector};
+
+// Where the first run of `marker_size` distinct characters ends.
+pub fn find_marker(buf: &str, marker_size: usize) -> Option<usize> {
+    return MarkerDetector::new(marker_size).feed(buf.as_bytes());
+}
+
+pub fn part1(input: &str) -> usize {
+    return find_marker(input, 4).unwrap();
+}
+
+pub fn part2(input: &str) -> usize {
+    return find_marker(input, 14).unwrap();
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let buf = input.trim();
+
+        // A run of 14 different characters also has a run of 4, so both parts have an answer.
+        if find_marker(buf, 14).is_none() {
+            let column = buf.chars().count() + 1;
+            return Err(ParseError::new(
+                1,
+                column,
+                "a run of 14 different characters",
+                "",
+            ));
+        }
+        return Ok(String::from(buf));
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn all_distinct(window: &[u8]) -> bool {
+        return window
+            .iter()
+            .enumerate()
+            .all(|(i, a)| window[i + 1..].iter().all(|b| a != b));
+    }
+
+    #[test]
+    fn pt1_test() {
+        assert_eq!(part1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
+        assert_eq!(part1("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
+        assert_eq!(part1("nppdvjthqldpwncqszvftbrmjlhg"), 6);
+        assert_eq!(part1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
+        assert_eq!(part1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
+    }
+
+    #[test]
+    fn pt2_test() {
+        assert_eq!(part2("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
+        assert_eq!(part2("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
+        assert_eq!(part2("nppdvjthqldpwncqszvftbrmjlhg"), 23);
+        assert_eq!(part2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(part2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = Day6::parse("abcdabcd\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+        assert_eq!(err.expected, "a run of 14 different characters");
+        assert!(Day6::parse("").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn marker_ends_first_distinct_window(buf in "[a-f]{0,40}", marker_size in 1..7usize) {
+            let bytes = buf.as_bytes();
+            let first_end = (marker_size..=bytes.len())
+                .find(|&end| all_distinct(&bytes[end - marker_size..end]));
+            prop_assert_eq!(find_marker(&buf, marker_size), first_end);
+        }
+
+        #[test]
+        fn marker_found_after_repeats(repeats in 0..20usize) {
+            // The first window with no repeated character starts at the last `a`.
+            let buf = format!("{}bcdefghijklmn", "a".repeat(repeats + 1));
+            prop_assert_eq!(part1(&buf), repeats + 4);
+            prop_assert_eq!(part2(&buf), repeats + 14);
+        }
+    }
+}
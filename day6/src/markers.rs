@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Whether a marker can share symbols with the one before it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Overlap {
+    // Report every window of distinct symbols, however much it shares with earlier ones.
+    Allowed,
+
+    // Only start looking for the next marker after the end of the last one.
+    Separate,
+}
+
+// Where each marker of `size` distinct symbols ends, counting positions in symbols. This is the
+// same sliding window as `MarkerDetector`, with a map of where each symbol was last seen instead
+// of a table of bytes, so it works for any symbols that can be hashed.
+pub struct Markers<I: Iterator> {
+    symbols: I,
+    size: usize,
+    overlap: Overlap,
+    position: usize,
+    start: usize,
+    last_seen: HashMap<I::Item, usize>,
+}
+
+impl<I> Iterator for Markers<I>
+where
+    I: Iterator,
+    I::Item: Eq + Hash,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let symbol = self.symbols.next()?;
+            self.position += 1;
+            if let Some(seen) = self.last_seen.insert(symbol, self.position) {
+                self.start = self.start.max(seen);
+            }
+
+            if self.position - self.start >= self.size {
+                if self.overlap == Overlap::Separate {
+                    self.start = self.position;
+                }
+                return Some(self.position);
+            }
+        }
+    }
+}
+
+// Markers in any sequence of symbols, like the words from `split_whitespace`.
+pub fn markers<I>(symbols: I, size: usize, overlap: Overlap) -> Markers<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: Eq + Hash,
+{
+    assert!(size > 0, "A marker has to be at least one symbol long");
+    return Markers {
+        symbols: symbols.into_iter(),
+        size,
+        overlap,
+        position: 0,
+        start: 0,
+        last_seen: HashMap::new(),
+    };
+}
+
+// Markers in a string's bytes, as the puzzle counts them.
+pub fn byte_markers(buf: &str, size: usize, overlap: Overlap) -> Markers<std::str::Bytes<'_>> {
+    return markers(buf.bytes(), size, overlap);
+}
+
+// Markers in a string's Unicode scalar values, counting positions in characters.
+pub fn char_markers(buf: &str, size: usize, overlap: Overlap) -> Markers<std::str::Chars<'_>> {
+    return markers(buf.chars(), size, overlap);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_marker;
+    use proptest::prelude::*;
+
+    fn all_distinct(window: &[char]) -> bool {
+        return window
+            .iter()
+            .enumerate()
+            .all(|(i, a)| !window[i + 1..].contains(a));
+    }
+
+    #[test]
+    fn example_test() {
+        let buf = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let all: Vec<usize> = byte_markers(buf, 4, Overlap::Allowed).take(4).collect();
+        assert_eq!(all, vec![7, 8, 9, 10]);
+        let separate: Vec<usize> = byte_markers(buf, 4, Overlap::Separate).collect();
+        assert_eq!(separate, vec![7, 11, 15, 19, 23, 27]);
+    }
+
+    #[test]
+    fn alphabet_test() {
+        // Counted in characters, not the bytes they take up. These all start with the same byte,
+        // so no four bytes in a row are different.
+        let buf = "ααβγδε";
+        assert_eq!(char_markers(buf, 4, Overlap::Allowed).next(), Some(5));
+        assert_eq!(byte_markers(buf, 4, Overlap::Allowed).next(), None);
+
+        // Or in whatever symbols a tokenizer splits the input into.
+        let words = "up up down left right up down";
+        let ends: Vec<usize> = markers(words.split_whitespace(), 3, Overlap::Allowed).collect();
+        assert_eq!(ends, vec![4, 5, 6, 7]);
+    }
+
+    proptest! {
+        #[test]
+        fn markers_match_windows(buf in "[a-dé☃]{0,40}", size in 1..5usize) {
+            let chars: Vec<char> = buf.chars().collect();
+            let overlapping: Vec<usize> = (size..=chars.len())
+                .filter(|&end| all_distinct(&chars[end - size..end]))
+                .collect();
+            prop_assert_eq!(char_markers(&buf, size, Overlap::Allowed).collect::<Vec<_>>(), overlapping.clone());
+
+            // Taking the earliest marker that doesn't overlap the last one each time.
+            let mut separate = Vec::new();
+            for &end in &overlapping {
+                if separate.last().is_none_or(|&last| end - size >= last) {
+                    separate.push(end);
+                }
+            }
+            prop_assert_eq!(char_markers(&buf, size, Overlap::Separate).collect::<Vec<_>>(), separate);
+
+            let first = byte_markers(&buf, size, Overlap::Allowed).next();
+            prop_assert_eq!(first, find_marker(&buf, size));
+        }
+    }
+}
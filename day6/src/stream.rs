@@ -0,0 +1,152 @@
+use std::io::{self, Read};
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+// Spots runs of `size` distinct bytes in a stream fed to it a byte at a time. A run can only
+// start after the last repeat of any byte in it, so remembering where each byte value was last
+// seen is enough to keep track of the current run in O(1) per byte, whatever the marker size.
+#[derive(Clone, Debug)]
+pub struct MarkerDetector {
+    size: usize,
+
+    // The number of bytes fed so far.
+    position: usize,
+
+    // The position just after each byte value was last seen, or 0 if it hasn't been.
+    last_seen: [usize; 256],
+
+    // Where the current run of distinct bytes starts.
+    start: usize,
+}
+
+impl MarkerDetector {
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "A marker has to be at least one byte long");
+        return MarkerDetector {
+            size,
+            position: 0,
+            last_seen: [0; 256],
+            start: 0,
+        };
+    }
+
+    pub fn position(&self) -> usize {
+        return self.position;
+    }
+
+    // Feed the next byte, returning the position just after it if it ends a marker: if it and the
+    // bytes before it make `size` distinct bytes.
+    pub fn push(&mut self, byte: u8) -> Option<usize> {
+        self.position += 1;
+        let seen = &mut self.last_seen[byte as usize];
+        self.start = self.start.max(*seen);
+        *seen = self.position;
+        return (self.position - self.start >= self.size).then_some(self.position);
+    }
+
+    // Feed bytes until one ends a marker, returning its position, or `None` if none of them do.
+    pub fn feed(&mut self, bytes: &[u8]) -> Option<usize> {
+        return bytes.iter().find_map(|&byte| self.push(byte));
+    }
+}
+
+// Find where the first marker of each size ends, reading the stream a buffer at a time and only
+// as far as the last of them.
+pub fn find_markers_in<R: Read>(mut reader: R, sizes: &[usize]) -> io::Result<Vec<Option<usize>>> {
+    let mut detectors: Vec<MarkerDetector> = sizes
+        .iter()
+        .map(|&size| MarkerDetector::new(size))
+        .collect();
+    let mut found: Vec<Option<usize>> = vec![None; sizes.len()];
+    let mut buf = vec![0; BUFFER_SIZE];
+
+    while found.contains(&None) {
+        let read = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        for (detector, end) in detectors.iter_mut().zip(found.iter_mut()) {
+            if end.is_none() {
+                *end = detector.feed(&buf[..read]);
+            }
+        }
+    }
+    return Ok(found);
+}
+
+// Find where the first marker of `size` distinct bytes ends in a stream.
+pub fn find_marker_in<R: Read>(reader: R, size: usize) -> io::Result<Option<usize>> {
+    return Ok(find_markers_in(reader, &[size])?[0]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Hands out its bytes a few at a time, being interrupted along the way.
+    struct Trickle {
+        bytes: Vec<u8>,
+        chunks: Vec<usize>,
+        reads: usize,
+    }
+
+    impl Read for Trickle {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reads += 1;
+            if self.reads.is_multiple_of(3) {
+                return Err(io::Error::from(io::ErrorKind::Interrupted));
+            }
+            let len = self.chunks[self.reads % self.chunks.len()]
+                .min(buf.len())
+                .min(self.bytes.len());
+            buf[..len].copy_from_slice(&self.bytes[..len]);
+            self.bytes.drain(..len);
+            return Ok(len);
+        }
+    }
+
+    #[test]
+    fn long_stream_test() {
+        // A long run of repeats doesn't need to be held in memory.
+        let repeats = io::repeat(b'a').take(10_000_000);
+        let reader = repeats.chain(&b"bcdefghijklmnxyz"[..]);
+        assert_eq!(
+            find_markers_in(reader, &[4, 14, 18]).unwrap(),
+            vec![Some(10_000_003), Some(10_000_013), None]
+        );
+    }
+
+    #[test]
+    fn error_test() {
+        struct Broken;
+        impl Read for Broken {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                return Err(io::Error::other("disconnected"));
+            }
+        }
+        assert_eq!(
+            find_marker_in(Broken, 4).unwrap_err().to_string(),
+            "disconnected"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn stream_matches_whole_buffer(
+            buf in "[a-f]{0,60}",
+            marker_size in 1..7usize,
+            chunks in prop::collection::vec(1..5usize, 1..4),
+        ) {
+            let trickle = Trickle { bytes: buf.as_bytes().to_vec(), chunks, reads: 0 };
+            let first_end = (marker_size..=buf.len()).find(|&end| {
+                let window = &buf.as_bytes()[end - marker_size..end];
+                window.iter().enumerate().all(|(i, a)| !window[i + 1..].contains(a))
+            });
+            prop_assert_eq!(find_marker_in(trickle, marker_size).unwrap(), first_end);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+use day7::Day7;
+
+aoc_common::bench_main!(Day7, ["input"]);
@@ -0,0 +1,277 @@
+use aoc_common::{parse_non_empty_lines, ParseError, Solution, SolveError};
+use std::collections::HashMap;
+
+const MAX_SIZE: usize = 100000;
+const DISK_SIZE: usize = 70000000;
+const REQUIRED_SIZE: usize = 30000000;
+
+fn handle_cd(dir: &str, cur_dirs: &mut Vec<String>) {
+    if dir == "/" {
+        cur_dirs.clear();
+        cur_dirs.push(String::from("/"));
+    } else if dir == ".." {
+        cur_dirs.pop();
+    } else {
+        cur_dirs.push(String::from(dir));
+    }
+}
+
+fn handle_file(
+    line_number: usize,
+    line: &str,
+    cur_dirs: &Vec<String>,
+    sizes: &mut HashMap<String, usize>,
+) -> Result<(), ParseError> {
+    let size = line
+        .split(' ')
+        .next()
+        .and_then(|v| v.parse::<usize>().ok())
+        .ok_or_else(|| ParseError::in_line(line_number, line, 0, "a command or file size"))?;
+
+    // Directories are keyed by their full path, like `/a/e`.
+    let mut full_dir = String::new();
+    for (depth, dir) in cur_dirs.iter().enumerate() {
+        if depth > 1 {
+            full_dir.push('/');
+        }
+        full_dir.push_str(dir.as_str());
+        match sizes.get_mut(&full_dir) {
+            Some(s) => *s += size,
+            None => {
+                sizes.insert(full_dir.clone(), size);
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+pub fn dir_sizes(lines: &Vec<String>) -> Result<HashMap<String, usize>, ParseError> {
+    let mut cur_dirs = vec![String::from("/")];
+    let mut sizes = HashMap::from([(String::from("/"), 0)]);
+
+    for (index, line) in lines.iter().enumerate() {
+        if let Some(dir) = line.strip_prefix("$ cd ") {
+            handle_cd(dir, &mut cur_dirs);
+        } else if line != "$ ls" && !line.starts_with("dir ") {
+            handle_file(index + 1, line, &cur_dirs, &mut sizes)?;
+            if sizes["/"] > DISK_SIZE {
+                let expected = format!("files totalling at most {} bytes", DISK_SIZE);
+                return Err(ParseError::in_line(index + 1, line, 0, expected));
+            }
+        }
+    }
+
+    return Ok(sizes);
+}
+
+pub fn part1(sizes: &HashMap<String, usize>) -> usize {
+    let mut result = 0;
+
+    for size in sizes.values() {
+        if *size <= MAX_SIZE {
+            result += size;
+        }
+    }
+
+    return result;
+}
+
+pub fn part2(sizes: &HashMap<String, usize>) -> usize {
+    // There might already be enough space, in which case any directory will do. Otherwise the root
+    // directory is always big enough, as the files fit on the disk.
+    let total_size = sizes["/"];
+    let min_free = REQUIRED_SIZE.saturating_sub(DISK_SIZE - total_size);
+    return sizes
+        .values()
+        .copied()
+        .filter(|&s| s >= min_free)
+        .min()
+        .expect("The root directory is always big enough");
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = HashMap<String, usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, dir_sizes);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeMap;
+
+    #[derive(Clone, Debug)]
+    struct Dir {
+        files: Vec<usize>,
+        dirs: BTreeMap<String, Dir>,
+    }
+
+    fn tree() -> impl Strategy<Value = Dir> {
+        let files = prop::collection::vec(1..60000usize, 0..4);
+        let leaf = files.clone().prop_map(|files| Dir {
+            files,
+            dirs: BTreeMap::new(),
+        });
+        return leaf.prop_recursive(4, 32, 4, move |inner| {
+            (
+                files.clone(),
+                prop::collection::btree_map("[a-c]{1,2}", inner, 0..4),
+            )
+                .prop_map(|(files, dirs)| Dir { files, dirs })
+        });
+    }
+
+    // The terminal output from exploring the whole tree, depth first.
+    fn transcript(dir: &Dir, lines: &mut Vec<String>) {
+        lines.push(String::from("$ ls"));
+        for name in dir.dirs.keys() {
+            lines.push(format!("dir {}", name));
+        }
+        for (index, size) in dir.files.iter().enumerate() {
+            lines.push(format!("{} f{}.txt", size, index));
+        }
+        for (name, child) in &dir.dirs {
+            lines.push(format!("$ cd {}", name));
+            transcript(child, lines);
+            lines.push(String::from("$ cd .."));
+        }
+    }
+
+    // The total size of each directory in the tree, including the root.
+    fn totals(dir: &Dir, out: &mut Vec<usize>) -> usize {
+        let mut total: usize = dir.files.iter().sum();
+        for child in dir.dirs.values() {
+            total += totals(child, out);
+        }
+        out.push(total);
+        return total;
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = vec![
+            String::from("$ cd /"),
+            String::from("$ ls"),
+            String::from("dir a"),
+            String::from("14848514 b.txt"),
+            String::from("8504156 c.dat"),
+            String::from("dir d"),
+            String::from("$ cd a"),
+            String::from("$ ls"),
+            String::from("dir e"),
+            String::from("29116 f"),
+            String::from("2557 g"),
+            String::from("62596 h.lst"),
+            String::from("$ cd e"),
+            String::from("$ ls"),
+            String::from("584 i"),
+            String::from("$ cd .."),
+            String::from("$ cd .."),
+            String::from("$ cd d"),
+            String::from("$ ls"),
+            String::from("4060174 j"),
+            String::from("8033020 d.log"),
+            String::from("5626152 d.ext"),
+            String::from("7214296 k"),
+        ];
+
+        let result = part1(&dir_sizes(&lines).unwrap());
+        assert_eq!(result, 95437);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = vec![
+            String::from("$ cd /"),
+            String::from("$ ls"),
+            String::from("dir a"),
+            String::from("14848514 b.txt"),
+            String::from("8504156 c.dat"),
+            String::from("dir d"),
+            String::from("$ cd a"),
+            String::from("$ ls"),
+            String::from("dir e"),
+            String::from("29116 f"),
+            String::from("2557 g"),
+            String::from("62596 h.lst"),
+            String::from("$ cd e"),
+            String::from("$ ls"),
+            String::from("584 i"),
+            String::from("$ cd .."),
+            String::from("$ cd .."),
+            String::from("$ cd d"),
+            String::from("$ ls"),
+            String::from("4060174 j"),
+            String::from("8033020 d.log"),
+            String::from("5626152 d.ext"),
+            String::from("7214296 k"),
+        ];
+
+        let result = part2(&dir_sizes(&lines).unwrap());
+        assert_eq!(result, 24933642);
+    }
+
+    #[test]
+    fn disk_size_test() {
+        // Nothing needs deleting from an empty disk, or from a full one it's all of it.
+        let sizes = Day7::parse("$ cd /\n$ ls\n").unwrap();
+        assert_eq!(part2(&sizes), 0);
+        let sizes = Day7::parse("$ cd /\n$ ls\n70000000 a\n").unwrap();
+        assert_eq!(part2(&sizes), 70000000);
+
+        let err = Day7::parse("$ cd /\n$ ls\n70000000 a\n1 b\n").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "files totalling at most 70000000 bytes");
+    }
+
+    #[test]
+    fn full_path_test() {
+        // `/a` then `b` and `/ab` used to be recorded under the same key.
+        let lines = [
+            "$ cd /", "$ ls", "dir a", "dir ab", "$ cd a", "$ ls", "dir b", "$ cd b", "$ ls",
+            "10 x", "$ cd ..", "$ cd ..", "$ cd ab", "$ ls", "20 y",
+        ];
+        let sizes = dir_sizes(&lines.map(String::from).to_vec()).unwrap();
+        assert_eq!(sizes["/a/b"], 10);
+        assert_eq!(sizes["/ab"], 20);
+        assert_eq!(part1(&sizes), 10 + 10 + 20 + 30);
+    }
+
+    proptest! {
+        #[test]
+        fn sizes_match_tree(root in tree()) {
+            let mut lines = vec![String::from("$ cd /")];
+            transcript(&root, &mut lines);
+            let sizes = dir_sizes(&lines).unwrap();
+
+            // Directories with no files anywhere below them are never recorded.
+            let mut expected = Vec::new();
+            let total = totals(&root, &mut expected);
+            expected.retain(|&size| size > 0);
+            expected.sort();
+            let mut actual: Vec<usize> = sizes.values().copied().filter(|&size| size > 0).collect();
+            actual.sort();
+
+            prop_assert_eq!(sizes["/"], total);
+            prop_assert_eq!(&actual, &expected);
+            let small: usize = expected.iter().filter(|&&size| size <= MAX_SIZE).sum();
+            prop_assert_eq!(part1(&sizes), small);
+        }
+    }
+}
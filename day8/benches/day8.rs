@@ -0,0 +1,3 @@
+use day8::Day8;
+
+aoc_common::bench_main!(Day8, ["input"]);
@@ -0,0 +1,319 @@
+use aoc_common::{parse_non_empty_lines, Grid, ParseError, Solution, SolveError};
+use std::cmp;
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Visibility {
+    north: usize,
+    east: usize,
+    south: usize,
+    west: usize,
+}
+
+pub fn parse_lines(lines: &Vec<String>) -> Result<Grid<usize>, ParseError> {
+    if lines.is_empty() {
+        return Err(ParseError::new(1, 1, "a row of trees", ""));
+    }
+
+    return Grid::parse(lines, "a tree height", |c| {
+        c.to_digit(10).map(|height| height as usize)
+    });
+}
+
+// Find the distance from tree at a given coord, to the nearest tree that blocks it.
+fn find_blocking_tree(
+    nearest_trees: [Option<usize>; 10],
+    height: usize,
+    position: usize,
+) -> Option<usize> {
+    let mut distance: Option<usize> = None;
+
+    // We have the nearest tree to us for each height from 0-10. Loop through the ones that are
+    // of equal height or taller to the current tree, and find the nearest.
+    for nearest in nearest_trees.iter().skip(height) {
+        distance = match (*nearest, distance) {
+            // We already found a blocking tree, and we have a new candidate - find out if it
+            // is nearer.
+            (Some(other_position), Some(cur_distance)) => {
+                let new_distance =
+                    (position as i32 - other_position as i32).unsigned_abs() as usize;
+                Some(cmp::min(new_distance, cur_distance))
+            }
+
+            // We have a tree that this height, and haven't found another tree yet, so this is
+            // the closest current blocking tree.
+            (Some(other_position), None) => {
+                Some((position as i32 - other_position as i32).unsigned_abs() as usize)
+            }
+
+            // No tree of this height, carry on.
+            (None, cur_distance) => cur_distance,
+        }
+    }
+
+    return distance;
+}
+
+// Calculate the scenic scores for each tree, and find the max over the entire forest.
+fn find_max_scenic_score(trees: &Grid<usize>) -> usize {
+    let height = trees.height();
+    let width = trees.width();
+
+    let mut visibility = Grid::new(width, height, Visibility::default());
+
+    // Keep track of the indices of the most recent tree of each size that we have
+    // seen while traversing in a given direction. For each tree we visit we can then
+    // look for any taller trees that would block visibility, and find the closest one.
+    // This allows us to calculate the max scene score in linear time.
+    let mut nearest_trees: [Option<usize>; 10];
+
+    for col_index in 0..width {
+        // Work down each column, calculating the northwards visibility
+        nearest_trees = [None; 10];
+        for row_index in 0..height {
+            let tree_height = trees[(col_index, row_index)];
+            let blocking = find_blocking_tree(nearest_trees, tree_height, row_index);
+            nearest_trees[tree_height] = Some(row_index);
+
+            // If we didn't find a blocking tree, visibility is the number of trees to the
+            // edge of the forest, which is the position.
+            visibility[(col_index, row_index)].north = blocking.unwrap_or(row_index);
+        }
+
+        // Work up each column, calculating the southwards visibility
+        nearest_trees = [None; 10];
+        for row_index in (0..height).rev() {
+            let tree_height = trees[(col_index, row_index)];
+            let blocking = find_blocking_tree(nearest_trees, tree_height, row_index);
+
+            // If we didn't find a blocking tree, visibility is the number of trees to the
+            // edge of the forest, which can be calculated from the position
+            visibility[(col_index, row_index)].south = blocking.unwrap_or(height - row_index - 1);
+
+            nearest_trees[tree_height] = Some(row_index);
+        }
+    }
+
+    for row_index in 0..height {
+        // Work left to right along each row, calculating westwards visibility
+        nearest_trees = [None; 10];
+        for col_index in 0..width {
+            let tree_height = trees[(col_index, row_index)];
+            let blocking = find_blocking_tree(nearest_trees, tree_height, col_index);
+
+            // Visibility calc as for the northward case, but with column index.
+            visibility[(col_index, row_index)].west = blocking.unwrap_or(col_index);
+
+            nearest_trees[tree_height] = Some(col_index);
+        }
+
+        // Work right to left along each row, calculating eastwards visibility
+        nearest_trees = [None; 10];
+        for col_index in (0..width).rev() {
+            let tree_height = trees[(col_index, row_index)];
+            let blocking = find_blocking_tree(nearest_trees, tree_height, col_index);
+
+            // Visilibity calc as for the southwards case, but with column index.
+            visibility[(col_index, row_index)].east = blocking.unwrap_or(width - col_index - 1);
+
+            nearest_trees[tree_height] = Some(col_index);
+        }
+    }
+
+    return visibility
+        .iter()
+        .map(|(_, v)| v.north * v.east * v.south * v.west)
+        .max()
+        .expect("Failed to find max score");
+}
+
+// Helper function for count_visible
+fn update_visible(visible: &mut bool, max_height: Option<usize>, height: usize) -> Option<usize> {
+    if let Some(h) = max_height {
+        if height > h {
+            *visible = true;
+            return Some(height);
+        }
+    } else {
+        *visible = true;
+        return Some(height);
+    }
+
+    return max_height;
+}
+
+// Count the number of trees visible from the outside of the forest
+fn count_visible(trees: &Grid<usize>) -> usize {
+    let height = trees.height();
+    let width = trees.width();
+
+    // Whether the tree at the given coordinate is visible.
+    let mut visible = Grid::new(width, height, false);
+
+    // The maximum height we've seen in each column.
+    let mut max_height: Option<usize>;
+
+    for col_index in 0..width {
+        // Work down each column
+        max_height = None;
+        for row_index in 0..height {
+            max_height = update_visible(
+                &mut visible[(col_index, row_index)],
+                max_height,
+                trees[(col_index, row_index)],
+            );
+        }
+
+        // Work up each column
+        max_height = None;
+        for row_index in (0..height).rev() {
+            max_height = update_visible(
+                &mut visible[(col_index, row_index)],
+                max_height,
+                trees[(col_index, row_index)],
+            );
+        }
+    }
+
+    for row_index in 0..height {
+        // Work along each column left to right
+        max_height = None;
+        for col_index in 0..width {
+            max_height = update_visible(
+                &mut visible[(col_index, row_index)],
+                max_height,
+                trees[(col_index, row_index)],
+            );
+        }
+
+        // Work along each column right to left
+        max_height = None;
+        for col_index in (0..width).rev() {
+            max_height = update_visible(
+                &mut visible[(col_index, row_index)],
+                max_height,
+                trees[(col_index, row_index)],
+            );
+        }
+    }
+
+    return visible.iter().filter(|(_, &v)| v).count();
+}
+
+pub fn part1(trees: &Grid<usize>) -> usize {
+    return count_visible(trees);
+}
+
+pub fn part2(trees: &Grid<usize>) -> usize {
+    return find_max_scenic_score(trees);
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::DIRECTIONS4;
+    use proptest::prelude::*;
+
+    fn forest() -> impl Strategy<Value = Vec<String>> {
+        return (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+            let row = prop::string::string_regex(&format!("[0-9]{{{}}}", width)).unwrap();
+            return prop::collection::vec(row, height);
+        });
+    }
+
+    // Walk out from every tree in each direction rather than sweeping the rows and columns.
+    fn brute_force(trees: &Grid<usize>) -> (usize, usize) {
+        let mut visible = 0;
+        let mut max_score = 0;
+        for (pos, &height) in trees.iter() {
+            let mut is_visible = false;
+            let mut score = 1;
+            for dir in DIRECTIONS4 {
+                let mut distance = 0;
+                let mut blocked = false;
+                for other in trees.ray(pos, dir).map(|other| trees[other]) {
+                    distance += 1;
+                    if other >= height {
+                        blocked = true;
+                        break;
+                    }
+                }
+                is_visible |= !blocked;
+                score *= distance;
+            }
+            visible += is_visible as usize;
+            max_score = max_score.max(score);
+        }
+        return (visible, max_score);
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = vec![
+            String::from("30373"),
+            String::from("25512"),
+            String::from("65332"),
+            String::from("33549"),
+            String::from("35390"),
+        ];
+
+        let trees = parse_lines(&lines).unwrap();
+        let result = part1(&trees);
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn pt2_test() {
+        let lines = vec![
+            String::from("30373"),
+            String::from("25512"),
+            String::from("65332"),
+            String::from("33549"),
+            String::from("35390"),
+        ];
+
+        let trees = parse_lines(&lines).unwrap();
+        let result = part2(&trees);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = Day8::parse("").unwrap_err();
+        assert_eq!(err.expected, "a row of trees");
+    }
+
+    proptest! {
+        #[test]
+        fn parts_match_brute_force(lines in forest()) {
+            let trees = parse_lines(&lines).unwrap();
+            let (visible, max_score) = brute_force(&trees);
+            prop_assert_eq!(part1(&trees), visible);
+            prop_assert_eq!(part2(&trees), max_score);
+
+            // Every tree on the edge can be seen.
+            let (width, height) = (trees.width(), trees.height());
+            let edge = if width < 3 || height < 3 { width * height } else { 2 * (width + height) - 4 };
+            prop_assert!(visible >= edge);
+        }
+    }
+}
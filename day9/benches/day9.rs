@@ -0,0 +1,3 @@
+use day9::Day9;
+
+aoc_common::bench_main!(Day9, ["input"]);
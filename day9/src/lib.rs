@@ -0,0 +1,237 @@
+use aoc_common::{parse_non_empty_lines, ParseError, Solution, SolveError};
+use std::collections::HashSet;
+use std::iter;
+
+#[derive(Copy, Clone, Debug)]
+pub enum Operations {
+    Up(u8),
+    Right(u8),
+    Down(u8),
+    Left(u8),
+}
+
+pub fn parse_lines(lines: &Vec<String>) -> Result<Vec<Operations>, ParseError> {
+    return lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let line_number = index + 1;
+            let (dir, dist) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::in_line(line_number, line, line.len(), "a space"))?;
+
+            let dist = dist
+                .parse::<u8>()
+                .map_err(|_| ParseError::in_line(line_number, line, dir.len() + 1, "a distance"))?;
+            let result = match dir {
+                "U" => Some(Operations::Up(dist)),
+                "R" => Some(Operations::Right(dist)),
+                "D" => Some(Operations::Down(dist)),
+                "L" => Some(Operations::Left(dist)),
+                _ => None,
+            };
+
+            result.ok_or_else(|| ParseError::in_line(line_number, line, 0, "`U`, `R`, `D` or `L`"))
+        })
+        .collect();
+}
+
+fn move_tail(head_pos: (i32, i32), tail_pos: (i32, i32)) -> (i32, i32) {
+    let disp = (head_pos.0 - tail_pos.0, head_pos.1 - tail_pos.1);
+
+    // If the tail is touching the head, it stays where it is.
+    if disp.0.abs() <= 1 && disp.1.abs() <= 1 {
+        return tail_pos;
+    }
+
+    return (
+        tail_pos.0 + disp.0.clamp(-1, 1),
+        tail_pos.1 + disp.1.clamp(-1, 1),
+    );
+}
+
+fn count_tail_positions(ops: &Vec<Operations>, knot_count: usize) -> usize {
+    let mut visited: HashSet<(i32, i32)> = HashSet::from([(0, 0)]);
+
+    let mut locations: Vec<(i32, i32)> = iter::repeat_with(|| (0, 0)).take(knot_count).collect();
+
+    for op in ops {
+        let ((x_inc, y_inc), distance) = match op {
+            Operations::Up(distance) => ((0, 1), distance),
+            Operations::Right(distance) => ((1, 0), distance),
+            Operations::Down(distance) => ((0, -1), distance),
+            Operations::Left(distance) => ((-1, 0), distance),
+        };
+
+        for _ in 0..*distance {
+            locations[0].0 += x_inc;
+            locations[0].1 += y_inc;
+
+            for i in 1..knot_count {
+                locations[i] = move_tail(locations[i - 1], locations[i]);
+            }
+
+            visited.insert(locations[knot_count - 1]);
+        }
+    }
+
+    return visited.len();
+}
+
+pub fn part1(ops: &Vec<Operations>) -> usize {
+    return count_tail_positions(ops, 2);
+}
+
+pub fn part2(ops: &Vec<Operations>) -> usize {
+    return count_tail_positions(ops, 10);
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Operations>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_non_empty_lines(input, parse_lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        return Ok(part1(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        return Ok(part2(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn moves() -> impl Strategy<Value = Vec<(char, u8)>> {
+        return prop::collection::vec(
+            (prop::sample::select(vec!['U', 'R', 'D', 'L']), 0..10u8),
+            0..60,
+        );
+    }
+
+    fn parse_moves(moves: &[(char, u8)]) -> Vec<Operations> {
+        let lines: Vec<String> = moves
+            .iter()
+            .map(|(dir, dist)| format!("{} {}", dir, dist))
+            .collect();
+        return parse_lines(&lines).unwrap();
+    }
+
+    #[test]
+    fn pt1_test() {
+        let lines = vec![
+            String::from("R 4"),
+            String::from("U 4"),
+            String::from("L 3"),
+            String::from("D 1"),
+            String::from("R 4"),
+            String::from("D 1"),
+            String::from("L 5"),
+            String::from("R 2"),
+        ];
+        let ops = parse_lines(&lines).unwrap();
+        let result = part1(&ops);
+
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn pt2_test_a() {
+        let lines = vec![
+            String::from("R 4"),
+            String::from("U 4"),
+            String::from("L 3"),
+            String::from("D 1"),
+            String::from("R 4"),
+            String::from("D 1"),
+            String::from("L 5"),
+            String::from("R 2"),
+        ];
+        let ops = parse_lines(&lines).unwrap();
+        let result = part2(&ops);
+
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn pt2_test_b() {
+        let lines = vec![
+            String::from("R 5"),
+            String::from("U 8"),
+            String::from("L 8"),
+            String::from("D 3"),
+            String::from("R 17"),
+            String::from("D 10"),
+            String::from("L 25"),
+            String::from("U 20"),
+        ];
+        let ops = parse_lines(&lines).unwrap();
+        let result = part2(&ops);
+
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let lines = vec![String::from("R 4"), String::from("X 4")];
+        let err = parse_lines(&lines).unwrap_err();
+        assert_eq!(err, ParseError::new(2, 1, "`U`, `R`, `D` or `L`", "X"));
+
+        let lines = vec![String::from("R four")];
+        let err = parse_lines(&lines).unwrap_err();
+        assert_eq!(err, ParseError::new(1, 3, "a distance", "four"));
+    }
+
+    proptest! {
+        #[test]
+        fn straight_line_trails_head(dir in prop::sample::select(vec!['U', 'R', 'D', 'L']), dists in prop::collection::vec(1..20u8, 1..5)) {
+            let moves: Vec<(char, u8)> = dists.iter().map(|&dist| (dir, dist)).collect();
+            let ops = parse_moves(&moves);
+            let steps: usize = dists.iter().map(|&dist| dist as usize).sum();
+
+            // Each knot trails one step behind the one in front, so the tail visits every
+            // position the head did apart from the last few.
+            prop_assert_eq!(part1(&ops), steps);
+            prop_assert_eq!(part2(&ops), steps.saturating_sub(8).max(1));
+        }
+
+        #[test]
+        fn rotating_moves_keeps_counts(moves in moves()) {
+            let rotated: Vec<(char, u8)> = moves
+                .iter()
+                .map(|&(dir, dist)| {
+                    let dir = match dir {
+                        'U' => 'R',
+                        'R' => 'D',
+                        'D' => 'L',
+                        _ => 'U',
+                    };
+                    return (dir, dist);
+                })
+                .collect();
+
+            let (ops, rotated) = (parse_moves(&moves), parse_moves(&rotated));
+            prop_assert_eq!(part1(&ops), part1(&rotated));
+            prop_assert_eq!(part2(&ops), part2(&rotated));
+        }
+
+        #[test]
+        fn tail_positions_bounded(moves in moves()) {
+            let ops = parse_moves(&moves);
+            let steps: usize = moves.iter().map(|&(_, dist)| dist as usize).sum();
+            for knots in 2..=10 {
+                let count = count_tail_positions(&ops, knots);
+                prop_assert!((1..=steps + 1).contains(&count));
+            }
+        }
+    }
+}